@@ -322,6 +322,11 @@ class Colour extends Encodable {
         return `hsla(${this.h}, ${this.s}%, ${this.l}%, ${this.a})`;
     }
 
+    /// Returns the colour in the form `#rrggbb`, ignoring the alpha channel.
+    hex() {
+        return `#${this.rgba().slice(0, 3).map((x) => x.toString(16).padStart(2, "0")).join("")}`;
+    }
+
     /// Returns the LaTeX code corresponding to a HSL colour.
     latex(latex_colours, parenthesise = false) {
         // If the colour has a specific name in LaTeX (e.g. because it is predefined, or has been
@@ -196,6 +196,11 @@ class Quiver {
     /// - "tikz-cd"
     /// - "base64"
     /// - "html"
+    /// - "dot"
+    /// - "typst"
+    /// - "xypic"
+    /// - "text"
+    /// - "alt-text"
     /// `settings` describes persistent user settings (like whether to centre the diagram);
     /// `options` describes non-persistent user settings and diagram attributes (like the macro
     /// URL, and the dimensions of the diagram);
@@ -203,11 +208,21 @@ class Quiver {
     export(format, settings, options, definitions) {
         switch (format) {
             case "tikz-cd":
-                return QuiverExport.tikz_cd.export(this, settings, options, definitions);
+                return QuiverImportExport.tikz_cd.export(this, settings, options, definitions);
             case "base64":
                 return QuiverImportExport.base64.export(this, settings, options, definitions);
             case "html":
                 return QuiverExport.html.export(this, settings, options, definitions);
+            case "dot":
+                return QuiverImportExport.dot.export(this, settings, options, definitions);
+            case "typst":
+                return QuiverExport.typst.export(this, settings, options, definitions);
+            case "xypic":
+                return QuiverExport.xypic.export(this, settings, options, definitions);
+            case "text":
+                return QuiverImportExport.text.export(this, settings, options, definitions);
+            case "alt-text":
+                return QuiverExport.alt_text.export(this, settings, options, definitions);
             default:
                 throw new Error(`unknown export format \`${format}\``);
         }
@@ -218,15 +233,119 @@ class Quiver {
 class QuiverExport {
     /// A method to export a quiver as a string.
     export() {}
+
+    /// Recursively computes a delta between an `object` and `base`, e.g. between the options of an
+    /// edge and the default options, so that only the options that have changed are exported.
+    static delta(object, base) {
+        const delta = {};
+        for (const [key, value] of Object.entries(object)) {
+            const default_value = base[key];
+            if (default_value instanceof Encodable && value instanceof Encodable) {
+                if (!default_value.eq(value)) {
+                    delta[key] = value;
+                }
+            } else if (typeof default_value === "object" && typeof value === "object") {
+                const subdelta = QuiverExport.delta(value, default_value);
+                if (Object.keys(subdelta).length > 0) {
+                    delta[key] = subdelta;
+                }
+            } else if (default_value !== value) {
+                delta[key] = value;
+            }
+        }
+        return delta;
+    }
+
+    /// Lays out the vertices of `quiver` as the entries of a LaTeX matrix, relative to the top-left
+    /// vertex, with `&` separating entries and `\\` separating rows. `entry` returns the code for
+    /// each vertex. Returns the `output`, along with the position of the top-left vertex as
+    /// `offset`.
+    static grid(quiver, entry) {
+        // Note that currently vertices may not share the same position,
+        // as in that case they will be overwritten.
+        let offset = new Position(Infinity, Infinity);
+        // Construct a grid for the vertices.
+        const rows = new Map();
+        for (const vertex of quiver.cells[0]) {
+            if (!rows.has(vertex.position.y)) {
+                rows.set(vertex.position.y, new Map());
+            }
+            rows.get(vertex.position.y).set(vertex.position.x, vertex);
+            offset = offset.min(vertex.position);
+        }
+        // Iterate through the rows and columns in order, outputting the code for each entry.
+        let output = "";
+        const prev = new Position(offset.x, offset.y);
+        for (const [y, row] of Array.from(rows).sort(([y1,], [y2,]) => y1 - y2)) {
+            if (y - prev.y > 0) {
+                output += ` ${"\\\\\n".repeat(y - prev.y)}`;
+            }
+            // This variable is really unnecessary, but it allows us to remove
+            // a leading space on a line, which makes things prettier.
+            let first_in_row = true;
+            for (const [x, vertex] of Array.from(row).sort(([x1,], [x2,]) => x1 - x2)) {
+                if (x - prev.x > 0) {
+                    output += `${!first_in_row ? " " : ""}${"&".repeat(x - prev.x)} `;
+                }
+                output += entry(vertex);
+                prev.x = x;
+                first_in_row = false;
+            }
+            prev.x = offset.x;
+            prev.y = y;
+        }
+        return { output, offset };
+    }
+
+    /// Describes the style of an edge that is not drawn as an arrow (i.e. an adjunction, pullback
+    /// or pushout), for reporting that a format cannot represent it.
+    static unsupported_style(edge) {
+        return edge.options.style.name === "adjunction" ? "adjunctions" : "pullbacks and pushouts";
+    }
 }
 
 class QuiverImportExport extends QuiverExport {
     /// A method to import a quiver as a string. `import(export(quiver))` should be the
     /// identity function. Currently `import` takes a `UI` into which to import directly.
     import() {}
+
+    /// Returns the position at which to place the top-left corner of an imported diagram, whose
+    /// vertices have the given positions (relative to the top-left corner). We place the diagram
+    /// at the focus point, unless that would overlap existing vertices, in which case we place it
+    /// to the right of the existing diagram.
+    static import_origin(ui, positions) {
+        const origin = ui.focus_position;
+        if (positions.some((position) => ui.positions.has(`${origin.add(position)}`))) {
+            const [[, y_min], [x_max,]] = ui.quiver.bounding_rect();
+            return new Position(x_max + 2, y_min);
+        }
+        return origin;
+    }
+
+    /// Lays out the cells that have been imported into the existing diagram. This should be called
+    /// once all the cells have been created, with `ui.buffer_updates` set during their creation.
+    static complete_import(ui, cells) {
+        // When cells are created, they are usually queued. We don't want any cells that have been
+        // imported to be queued.
+        for (const cell of cells) {
+            cell.element.query_selector("kbd.queue").class_list.remove("queue");
+        }
+
+        // Update all the affected columns and rows.
+        delay(() => ui.update_col_row_size(
+            ...cells.filter((cell) => cell.is_vertex()).map((vertex) => vertex.position)
+        ));
+
+        // Stop buffering updates, so that individual changes to cells will resize the grid.
+        ui.buffer_updates = false;
+
+        // If the quiver is now nonempty, some toolbar actions will be available.
+        ui.toolbar.update(ui);
+        ui.update_focus_tooltip();
+    }
 }
 
-QuiverExport.tikz_cd = new class extends QuiverExport {
+QuiverImportExport.tikz_cd = new class extends QuiverImportExport {
     export(quiver, settings, options, definitions) {
         let output = "";
 
@@ -274,43 +393,14 @@ QuiverExport.tikz_cd = new class extends QuiverExport {
         //    ...
 
         // Output the vertices.
-        // Note that currently vertices may not share the same position,
-        // as in that case they will be overwritten.
-        let offset = new Position(Infinity, Infinity);
-        // Construct a grid for the vertices.
-        const rows = new Map();
-        for (const vertex of quiver.cells[0]) {
-            if (!rows.has(vertex.position.y)) {
-                rows.set(vertex.position.y, new Map());
-            }
-            rows.get(vertex.position.y).set(vertex.position.x, vertex);
-            offset = offset.min(vertex.position);
-        }
-        // Iterate through the rows and columns in order, outputting the tikz-cd code.
-        const prev = new Position(offset.x, offset.y);
-        for (const [y, row] of Array.from(rows).sort(([y1,], [y2,]) => y1 - y2)) {
-            if (y - prev.y > 0) {
-                output += ` ${"\\\\\n".repeat(y - prev.y)}`;
-            }
-            // This variable is really unnecessary, but it allows us to remove
-            // a leading space on a line, which makes things prettier.
-            let first_in_row = true;
-            for (const [x, vertex] of Array.from(row).sort(([x1,], [x2,]) => x1 - x2)) {
-                if (x - prev.x > 0) {
-                    output += `${!first_in_row ? " " : ""}${"&".repeat(x - prev.x)} `;
-                }
-                if (vertex.label !== "" && vertex.label_colour.is_not_black()) {
-                    output += `\\textcolor${
-                        vertex.label_colour.latex(definitions.colours, true)}{${vertex.label}}`;
-                } else {
-                    output += !simple_label.test(vertex.label) ? `{${vertex.label}}` : vertex.label;
-                }
-                prev.x = x;
-                first_in_row = false;
+        const { output: grid, offset } = QuiverExport.grid(quiver, (vertex) => {
+            if (vertex.label !== "" && vertex.label_colour.is_not_black()) {
+                return `\\textcolor${
+                    vertex.label_colour.latex(definitions.colours, true)}{${vertex.label}}`;
             }
-            prev.x = offset.x;
-            prev.y = y;
-        }
+            return !simple_label.test(vertex.label) ? `{${vertex.label}}` : vertex.label;
+        });
+        output += grid;
 
         // Referencing cells is slightly complicated by the fact that we can't give vertices
         // names in tikz-cd, so we have to refer to them by position instead. That means 1-cells
@@ -688,149 +778,874 @@ QuiverExport.tikz_cd = new class extends QuiverExport {
             metadata: { tikz_incompatibilities },
         };
     }
-};
 
-QuiverImportExport.base64 = new class extends QuiverImportExport {
-    // The format we use for encoding quivers in base64 (primarily for link-sharing) is
-    // the following. This has been chosen based on minimality (for shorter representations),
-    // rather than readability.
-    //
-    // Note that an empty quiver has no representation.
-    //
-    // `[version: integer, |vertices|: integer, ...vertices, ...edges]`
-    //
-    // Parameters:
-    // - `version` is currently only permitted to be 0. The format has been designed to be
-    //   forwards-compatible with changes, so it is intended that this version will not
-    //   change.
-    // - `|vertices|` is the length of the array `vertices`.
-    // - `vertices` is an array of vertices of the form:
-    //      `[x: integer, y: integer, label: string, label_colour: [h, s, l, a]]`
-    //      + `label` is optional (if not present, it will default to `""`), though it must be
-    //         present if any later option is.
-    //      + `label_colour` is optional (if not present, it will default to `[0, 0, 0, 1]`).
-    //          + `h` is an integer from `0` to `360`
-    //          + `s` is an integer from `0` to `100`
-    //          + `l` is an integer from `0` to `100`
-    //          + `a` is a floating-point number from `0` to `1`
-    // - `edges` is an array of edges of the form:
-    //      `[source: index, target: index, label: string, alignment, options, label_colour]`
-    //      + (label) `alignment` is an enum comprising the following options:
-    //          * `0`: left
-    //          * `1`: centre
-    //          * `2`: right
-    //          * `3`: over
-    //        It has been distinguished from the other options as one that is frequently
-    //        changed from the default, to avoid the overhead of encoding an options
-    //        object.
-    //      + `options` is an object containing the delta of the options from the defaults.
-    //         This is the only parameter that is not encoded simply as an array, as the
-    //         most likely parameter to be changed in the future.
-    //      + `label_colour` is stored in the same manner as for vertices.
-    //
-    // Notes:
-    // - An `index` is an integer indexing into the array `[...vertices, ...edges]`.
-    // - Arrays may be truncated if the values of the elements are the default values.
+    /// Imports a `tikzcd` environment into the existing diagram, returning the new cells (as
+    /// `data`). tikz-cd is considerably more flexible than quiver, so this is only a one-sided
+    /// inverse of `export`: options that cannot be represented in quiver are skipped, and
+    /// reported in `metadata.tikz_incompatibilities`, rather than aborting the import.
+    import(ui, string) {
+        const tikz_incompatibilities = new Set();
 
-    export(quiver, _, options) {
-        // Remove the query string from the current URL and use that as a base.
-        const URL_prefix = window.location.href.replace(/\?.*$/, "");
+        // Helper functions for parsing.
 
-        if (quiver.is_empty()) {
-            // No need to have an encoding of an empty quiver;
-            // we'll just use the URL directly.
-            return {
-                data: URL_prefix,
-                metadata: {},
-            };
-        }
+        // Returns the index immediately after the group `{...}` or `[...]` that begins at
+        // `text[start]`. Brackets do not nest, but braces do (inside either kind of group).
+        const group_end = (text, start) => {
+            const closing = text[start] === "[" ? "]" : "}";
+            let depth = 0;
+            for (let i = start + 1; i < text.length; ++i) {
+                switch (text[i]) {
+                    case "\\":
+                        // Skip escaped characters, such as `\}`.
+                        ++i;
+                        break;
+                    case "{":
+                        ++depth;
+                        break;
+                    case "}":
+                        if (depth === 0 && closing === "}") {
+                            return i + 1;
+                        }
+                        --depth;
+                        break;
+                    case "]":
+                        if (depth === 0 && closing === "]") {
+                            return i + 1;
+                        }
+                        break;
+                }
+            }
+            throw new Error(`unmatched \`${text[start]}\``);
+        };
 
-        const cells = [];
-        const indices = new Map();
+        // Removes a pair of braces surrounding the entirety of `text`, if there is one.
+        const unwrap = (text) => {
+            text = text.trim();
+            if (text.startsWith("{") && group_end(text, 0) === text.length) {
+                return text.slice(1, -1).trim();
+            }
+            return text;
+        };
 
-        let offset = new Position(Infinity, Infinity);
-        // We want to ensure that the top-left cell is in position (0, 0), so we need
-        // to work out where the top-left cell actually is, to compute an offset.
-        for (const vertex of quiver.cells[0]) {
-            offset = offset.min(vertex.position);
-        }
-        for (const vertex of quiver.cells[0]) {
-            const { label, label_colour } = vertex;
-            indices.set(vertex, cells.length);
-            const position = vertex.position.sub(offset).toArray();
-            const cell = [...position];
-            // In the name of efficiency, we omit any parameter that is not necessary, and for which
-            // no later parameter is necessary.
-            if (label !== "") {
-                cell.push(label);
+        // Splits a TikZ option list at commas that are not inside braces or labels.
+        const split_options = (text) => {
+            const options = [];
+            let [start, depth, quoted] = [0, 0, false];
+            for (let i = 0; i < text.length; ++i) {
+                switch (text[i]) {
+                    case "\\":
+                        ++i;
+                        break;
+                    case "{":
+                        ++depth;
+                        break;
+                    case "}":
+                        --depth;
+                        break;
+                    case "\"":
+                        if (depth === 0) {
+                            quoted = !quoted;
+                        }
+                        break;
+                    case ",":
+                        if (depth === 0 && !quoted) {
+                            options.push(text.slice(start, i));
+                            start = i + 1;
+                        }
+                        break;
+                }
             }
-            if (label !== "" && label_colour.is_not_black()) {
-                // Even if the colour is not black, it's irrelevant if there is no label.
-                cell.push(label_colour.hsla());
+            options.push(text.slice(start));
+            return options.map((option) => option.trim()).filter((option) => option !== "");
+        };
+
+        // Splits an option into a key and a value (which is `null` if there is no value). Spacing
+        // in the key is normalised, so that, for instance, `shorten <= 2pt` has key `shorten <`.
+        const key_value = (option) => {
+            const index = option.indexOf("=");
+            if (index === -1) {
+                return [option.replace(/\s+/g, " "), null];
             }
-            cells.push(cell);
-        }
+            return [
+                option.slice(0, index).trim().replace(/\s+/g, " "),
+                unwrap(option.slice(index + 1)),
+            ];
+        };
 
-        for (let level = 1; level < quiver.cells.length; ++level) {
-            for (const edge of quiver.cells[level]) {
-                const { label, label_colour, options: { label_alignment, ...options } } = edge;
-                const [source, target] = [indices.get(edge.source), indices.get(edge.target)];
-                indices.set(edge, cells.length);
-                const cell = [source, target];
-                // We want to omit parameters that are unnecessary (i.e. have the default
-                // values). However, because we store parameters in an array, the only way
-                // we can distinguish missing values is by the length. Therefore, we can
-                // only truncate the array (not omit elements partway through the array).
-                // This means we may need to include unnecessary information if there is a
-                // non-default parameter after a default one. The parameters most likely to
-                // be default are placed further back in the array to reduce the frequency
-                // of this situation.
-                const end = [];
+        // Parses a colour, which may either be a colour code (as exported by quiver), or the name
+        // of a predefined colour, or one that has been imported along with the macros. Returns
+        // `null` (and reports the colour, if `report`) if the colour is not recognised.
+        const parse_colour = (text, report = true) => {
+            text = unwrap(text);
+            const code = text
+                .match(/^rgb,\s*255:\s*red,\s*(\d+);\s*green,\s*(\d+);\s*blue,\s*(\d+)$/);
+            if (code !== null) {
+                return Colour.from_rgba(...code.slice(1).map((x) => parseInt(x)));
+            }
+            if (ui.colours.has(text)) {
+                return ui.colours.get(text);
+            }
+            const predefined = new Map([
+                ["black", [0, 0, 0]],
+                ["red", [255, 0, 0]],
+                ["green", [0, 255, 0]],
+                ["blue", [0, 0, 255]],
+                ["white", [255, 255, 255]],
+            ]);
+            if (predefined.has(text)) {
+                return Colour.from_rgba(...predefined.get(text));
+            }
+            if (report) {
+                tikz_incompatibilities.add(`the colour \`${text}\``);
+            }
+            return null;
+        };
 
-                // Even if the colour is not black, it's irrelevant if there is no label.
-                if (label !== "" && label_colour.is_not_black()) {
-                    end.push(label_colour.hsla());
-                }
+        // Parses a TeX length, returning its value in `pt`, or `null` if it is not a length we
+        // recognise. `unit` is the length (in `pt`) of values that have no unit, which varies
+        // between TikZ options.
+        const parse_length = (text, unit = 1) => {
+            // The lengths of various units, in `pt`. Those that depend on the font size are given
+            // for the default font.
+            const UNITS = new Map([
+                ["", unit], ["pt", 1], ["mm", 2.845], ["cm", 28.45], ["in", 72.27], ["ex", 4.3],
+                ["em", 10],
+            ]);
+            const match = text.trim().match(/^([+-]?(?:\d+\.?\d*|\.\d+))\s*([a-z]*)$/);
+            if (match === null || !UNITS.has(match[2])) {
+                return null;
+            }
+            return parseFloat(match[1]) * UNITS.get(match[2]);
+        };
 
-                // We compute a delta of the edge options compared
-                // to the default, so we encode a minimum of data.
-                const default_options = Edge.default_options({ level });
+        // Parses the content of a label, which may be coloured using `\textcolor` (as quiver
+        // does for vertices).
+        const parse_label_content = (text) => {
+            let [label, colour] = [unwrap(text), null];
+            const textcolor = label.match(/^\\textcolor\s*(?=\{)/);
+            if (textcolor !== null) {
+                const colour_end = group_end(label, textcolor[0].length);
+                const content = label.slice(colour_end).trim();
+                if (content.startsWith("{") && group_end(content, 0) === content.length) {
+                    colour = parse_colour(label.slice(textcolor[0].length, colour_end));
+                    label = content.slice(1, -1).trim();
+                }
+            }
+            return { label, colour };
+        };
 
-                // Recursively compute a delta between an `object` and `base`.
-                const probe = (object, base) => {
-                    const delta = {};
-                    for (const [key, value] of Object.entries(object)) {
-                        const default_value = base[key];
-                        if (default_value instanceof Encodable && value instanceof Encodable) {
-                            if (!default_value.eq(value)) {
-                                delta[key] = value;
-                            }
-                        } else if (typeof default_value === "object" && typeof value === "object") {
-                            const subdelta = probe(value, default_value);
-                            if (Object.keys(subdelta).length > 0) {
-                                delta[key] = subdelta;
-                            }
-                        } else if (default_value !== value) {
-                            delta[key] = value;
-                        }
+        // Applies a label option pertaining to the position or alignment of a label, returning
+        // whether the option was recognised. These options may be given either for a specific
+        // label, or for an entire arrow (in which case they apply to every label).
+        const apply_label_option = (label, key, value) => {
+            const POSITIONS = new Map([
+                ["at start", 0],
+                ["very near start", 0.125],
+                ["near start", 0.25],
+                ["midway", 0.5],
+                ["near end", 0.75],
+                ["very near end", 0.875],
+                ["at end", 1],
+            ]);
+            if (POSITIONS.has(key) && value === null) {
+                label.position = POSITIONS.get(key);
+                return true;
+            }
+            switch (key) {
+                case "pos":
+                    const position = parseFloat(value);
+                    if (Number.isNaN(position)) {
+                        return false;
                     }
-                    return delta;
-                };
+                    label.position = position;
+                    return true;
+                case "swap":
+                    label.alignment = "right";
+                    return true;
+                case "description":
+                    label.alignment = "centre";
+                    return true;
+                case "marking":
+                    label.alignment = "over";
+                    return true;
+            }
+            return false;
+        };
 
-                const delta = probe(options, default_options);
-                if (end.length > 0 || Object.keys(delta).length > 0) {
-                    end.push(delta);
+        // Parses a label of the form `"content"'{options}` (where `'` and the options are
+        // optional, and the options need not be braced if there is only one).
+        const parse_label = (option) => {
+            // Find the closing quotation mark, which may not appear inside braces.
+            let end = 1;
+            for (let depth = 0; end < option.length; ++end) {
+                if (option[end] === "\\") {
+                    ++end;
+                } else if (option[end] === "{") {
+                    ++depth;
+                } else if (option[end] === "}") {
+                    --depth;
+                } else if (option[end] === "\"" && depth === 0) {
+                    break;
+                }
+            }
+            const label = {
+                ...parse_label_content(option.slice(1, end)),
+                alignment: null,
+                position: null,
+                name: null,
+            };
+            let rest = option.slice(end + 1).trim();
+            if (rest.startsWith("'")) {
+                label.alignment = "right";
+                rest = rest.slice(1).trim();
+            }
+            const options = rest.startsWith("{") ? split_options(unwrap(rest))
+                : (rest !== "" ? [rest] : []);
+            for (const option of options) {
+                const [key, value] = key_value(option);
+                if (apply_label_option(label, key, value)) {
+                    continue;
+                }
+                switch (key) {
+                    case "text":
+                    case "color":
+                        label.colour = parse_colour(value);
+                        break;
+                    case "name":
+                        label.name = value;
+                        break;
+                    // quiver uses these options for placeholder labels and decorations. They are
+                    // unnecessary once the decoration has been recognised.
+                    case "anchor":
+                    case "inner sep":
+                    case "rotate":
+                        break;
+                    default:
+                        tikz_incompatibilities.add(`the \`${key}\` label option`);
+                        break;
                 }
+            }
+            return label;
+        };
 
-                const push_if_necessary = (parameter, default_value, condition = true) => {
-                    if (end.length > 0 || (parameter !== default_value && condition)) {
-                        end.push(parameter);
-                    }
-                };
+        // Extract the `tikzcd` environment, ignoring comments (but not escaped percent signs).
+        string = string.replace(/(^|[^\\])%.*$/gm, "$1");
+        const environment = /\\begin\s*\{tikzcd\}([^]*?)\\end\s*\{tikzcd\}/.exec(string);
+        if (environment === null) {
+            throw new Error("no `tikzcd` environment found");
+        }
+        if (/\\begin\s*\{tikzcd\}/.test(string.slice(environment.index + environment[0].length))) {
+            tikz_incompatibilities.add("multiple `tikzcd` environments");
+        }
+        let body = environment[1];
 
-                const variant = { left: 0, centre: 1, right: 2, over: 3 }[label_alignment];
-                // It's only necessary to encode the label alignment if the label is not blank.
-                push_if_necessary(variant, 0, label !== "");
+        // Handle the options for the environment. None of these affect the structure of the
+        // diagram, except `ampersand replacement`, which changes the column separator.
+        let separator = "&";
+        const leading_space = body.match(/^\s*/)[0].length;
+        if (body[leading_space] === "[") {
+            const end = group_end(body, leading_space);
+            for (const option of split_options(body.slice(leading_space + 1, end - 1))) {
+                const [key, value] = key_value(option);
+                if (key === "ampersand replacement" && value !== null) {
+                    separator = value;
+                } else {
+                    tikz_incompatibilities.add(`the \`${key}\` diagram option`);
+                }
+            }
+            body = body.slice(end);
+        }
+
+        // Split the environment into vertices and arrows. We record the (0-indexed) row and column
+        // of each, since arrow directions are relative to the cell in which the arrow appears.
+        const vertices = [];
+        const arrows = [];
+        let [row, column, content] = [0, 0, ""];
+        const end_cell = () => {
+            content = content.trim();
+            if (content !== "") {
+                vertices.push({ row, column, content });
+            }
+            content = "";
+        };
+        const ROW_SEPARATOR = /\\\\\s*/y;
+        const ARROW = /\\(?:arrow|ar)(?![a-zA-Z])\s*/y;
+        // The shortcuts for arrows in a fixed direction, such as `\rar` for `\arrow[r]`.
+        const SHORTCUT = /\\(uu|dd|ll|rr|ur|ul|dr|dl|u|d|l|r)ar(?![a-zA-Z])\s*/y;
+        const CONTROL_SEQUENCE = /\\(?:[a-zA-Z]+|[^])/y;
+        let i = 0;
+        // Returns whether `regex` matches at index `i`, advancing `i` past the match if so.
+        const consume = (regex) => {
+            regex.lastIndex = i;
+            const match = regex.exec(body);
+            if (match !== null) {
+                i = regex.lastIndex;
+            }
+            return match;
+        };
+        while (i < body.length) {
+            let match;
+            if (body.startsWith(separator, i)) {
+                end_cell();
+                ++column;
+                i += separator.length;
+            } else if (consume(ROW_SEPARATOR) !== null) {
+                end_cell();
+                ++row;
+                column = 0;
+                // Skip any extra row spacing, e.g. `\\[2em]`.
+                if (body[i] === "[") {
+                    i = group_end(body, i);
+                }
+            } else if ((match = consume(ARROW) || consume(SHORTCUT)) !== null) {
+                // Arrows are usually given in the form `\arrow[r, "f"]`, but tikz-cd also supports
+                // an older form, `\arrow{r}[swap]{f}`, in which the direction and labels are given
+                // in groups following the options. We convert the older form into an option list.
+                const options = typeof match[1] !== "undefined" ? [match[1]] : [];
+                if (body[i] === "[") {
+                    const end = group_end(body, i);
+                    options.push(body.slice(i + 1, end - 1));
+                    i = end;
+                }
+                // Returns the index of the next non-whitespace character from `j`.
+                const skip_space = (j) => {
+                    while (j < body.length && /\s/.test(body[j])) {
+                        ++j;
+                    }
+                    return j;
+                };
+                // Shortcuts already have a direction, so any group following one is a label.
+                let older = typeof match[1] !== "undefined";
+                let j = skip_space(i);
+                if (!older && body[j] === "{") {
+                    const end = group_end(body, j);
+                    const direction = body.slice(j + 1, end - 1).trim();
+                    if (/^[udlr]+$/.test(direction)) {
+                        options.push(direction);
+                        i = end;
+                        older = true;
+                    }
+                }
+                // Labels in the older form are given as `{f}`, optionally preceded by label
+                // options, as in `[swap]{f}`.
+                for (j = skip_space(i); older && j < body.length; j = skip_space(i)) {
+                    let label_options = null;
+                    if (body[j] === "[") {
+                        const end = group_end(body, j);
+                        label_options = body.slice(j + 1, end - 1);
+                        j = skip_space(end);
+                    }
+                    if (body[j] !== "{") {
+                        break;
+                    }
+                    const end = group_end(body, j);
+                    options.push(`"${body.slice(j, end)}"${
+                        label_options !== null ? `{${label_options}}` : ""
+                    }`);
+                    i = end;
+                }
+                if (options.length > 0) {
+                    arrows.push({ row, column, options: options.join(", ") });
+                } else {
+                    tikz_incompatibilities.add("arrows without options");
+                }
+            } else if ((match = consume(CONTROL_SEQUENCE)) !== null) {
+                content += match[0];
+            } else if (body[i] === "{") {
+                const end = group_end(body, i);
+                content += body.slice(i, end);
+                i = end;
+            } else {
+                content += body[i++];
+            }
+        }
+        end_cell();
+
+        const origin = QuiverImportExport.import_origin(
+            ui,
+            vertices.map(({ row, column }) => new Position(column, row)),
+        );
+        const position_of = ({ row, column }) => origin.add(new Position(column, row));
+
+        // We don't want to relayout every time we add a new cell: instead, we should perform
+        // layout once, once all of the cells have been created.
+        ui.buffer_updates = true;
+
+        const cells = [];
+
+        // Vertices are referred to by their (1-indexed) position, e.g. `1-2`.
+        const references = new Map();
+        for (const vertex of vertices) {
+            let content = vertex.content;
+            // TikZ permits node options to be given for individual vertices, e.g. `|[draw]| A`.
+            const node_options = content.match(/^\|[^|]*\|/);
+            if (node_options !== null) {
+                tikz_incompatibilities.add("vertex options");
+                content = content.slice(node_options[0].length);
+            }
+            const { label, colour } = parse_label_content(content);
+            const cell = new Vertex(ui, label, position_of(vertex), colour || Colour.black());
+            references.set(`${vertex.row + 1}-${vertex.column + 1}`, cell);
+            cells.push(cell);
+        }
+
+        // The same multipliers as in `export`, which we use to invert the conversion of curves and
+        // shortening into absolute distances.
+        const TIKZ_HORIZONTAL_MULTIPLIER = 1/4;
+        const TIKZ_VERTICAL_MULTIPLIER = 1/6;
+        // The distance by which tikz-cd shifts arrows for `shift left=1` (i.e. `0.56ex`), in `pt`.
+        const TIKZ_SHIFT_DISTANCE = 0.56 * 4.3;
+
+        for (const arrow of arrows) {
+            const cell_at = (row, column) => references.get(`${row + 1}-${column + 1}`) || null;
+            let [source, target] = [cell_at(arrow.row, arrow.column), null];
+
+            const options = { offset: 0, curve: 0, level: 1 };
+            const style = {
+                name: "arrow",
+                tail: { name: "none" },
+                body: { name: "cell" },
+                head: { name: "arrowhead" },
+            };
+            // Shortening, in `pt`. This is converted to a proportion of the arrow length once we
+            // know the source and target.
+            const shorten = { source: 0, target: 0 };
+            let [colour, label_colour] = [null, null];
+            // Whether the arrow itself is drawn (rather than simply its labels).
+            let [drawn, phantom] = [true, false];
+            // Label options given for the arrow, rather than any specific label.
+            const arrow_label = { alignment: null, position: null };
+            const labels = [];
+
+            for (const option of split_options(arrow.options)) {
+                if (option.startsWith("\"")) {
+                    labels.push(parse_label(option));
+                    continue;
+                }
+
+                const [key, value] = key_value(option);
+
+                // Directions, such as `rr` or `dl`.
+                if (/^[udlr]+$/.test(key) && value === null) {
+                    const count = (direction) => key.split(direction).length - 1;
+                    target = cell_at(
+                        arrow.row + count("d") - count("u"),
+                        arrow.column + count("r") - count("l"),
+                    );
+                    continue;
+                }
+
+                if (apply_label_option(arrow_label, key, value)) {
+                    continue;
+                }
+
+                // A length option, which we can only handle if the length is valid.
+                const length = (unit = 1) => {
+                    const length = value !== null ? parse_length(value, unit) : null;
+                    if (length === null) {
+                        tikz_incompatibilities.add(`the value \`${value}\` for \`${key}\``);
+                    }
+                    return length;
+                };
+
+                // Edges are referred to by the names of their labels. A bare `to`, without a
+                // value, is instead the default arrow style.
+                if ((key === "from" || key === "to") && value !== null) {
+                    const cell = references.get(value) || null;
+                    if (key === "from") {
+                        source = cell;
+                    } else {
+                        target = cell;
+                    }
+                    continue;
+                }
+
+                switch (key) {
+                    // Colours.
+                    case "color":
+                        colour = label_colour = parse_colour(value);
+                        break;
+                    case "draw":
+                        if (value === "none") {
+                            drawn = false;
+                        } else {
+                            colour = parse_colour(value);
+                        }
+                        break;
+                    case "text":
+                        label_colour = parse_colour(value);
+                        break;
+                    case "phantom":
+                        [drawn, phantom] = [false, true];
+                        break;
+
+                    // Offset and curve.
+                    case "shift left":
+                    case "shift right":
+                        const shift = value !== null ?
+                            length(TIKZ_SHIFT_DISTANCE) : TIKZ_SHIFT_DISTANCE;
+                        if (shift !== null) {
+                            options.offset = Math.round(shift / TIKZ_SHIFT_DISTANCE)
+                                * (key === "shift left" ? -1 : 1);
+                        }
+                        break;
+                    case "curve":
+                        const height = value !== null && value.match(/^height\s*=\s*(.*)$/);
+                        const curve = height ? parse_length(unwrap(height[1])) : null;
+                        if (curve !== null) {
+                            options.curve = Math.round(
+                                curve / (CONSTANTS.CURVE_HEIGHT * TIKZ_HORIZONTAL_MULTIPLIER)
+                            );
+                        } else {
+                            tikz_incompatibilities.add(`the value \`${value}\` for \`${key}\``);
+                        }
+                        break;
+                    case "bend left":
+                    case "bend right":
+                        // quiver curves arrows by a fixed height, whereas TikZ bends arrows by an
+                        // angle, so we can only approximate bends.
+                        const angle = value !== null ? parseFloat(value) : 30;
+                        if (!Number.isNaN(angle)) {
+                            options.curve = Math.max(1, Math.round(2 * Math.sin(
+                                Math.min(Math.abs(angle), 90) * Math.PI / 180
+                            ))) * Math.sign(angle) * (key === "bend left" ? -1 : 1);
+                        } else {
+                            tikz_incompatibilities.add(`the value \`${value}\` for \`${key}\``);
+                        }
+                        break;
+                    case "shorten <":
+                    case "shorten >":
+                    case "shorten":
+                        const distance = length();
+                        if (distance !== null) {
+                            if (key !== "shorten >") {
+                                shorten.source = distance;
+                            }
+                            if (key !== "shorten <") {
+                                shorten.target = distance;
+                            }
+                        }
+                        break;
+
+                    // Levels.
+                    case "Rightarrow":
+                        options.level = 2;
+                        break;
+                    case "equal":
+                        options.level = 2;
+                        style.head.name = "none";
+                        break;
+
+                    // Body styles.
+                    case "dashed":
+                    case "dotted":
+                    case "squiggly":
+                        style.body.name = key;
+                        break;
+                    case "rightsquigarrow":
+                        style.body.name = "squiggly";
+                        break;
+                    case "no body":
+                        style.body.name = "none";
+                        break;
+
+                    // Tail styles.
+                    case "maps to":
+                    case "mapsto":
+                        style.tail.name = "maps to";
+                        break;
+                    case "tail":
+                    case "2tail":
+                    case "rightarrowtail":
+                        style.tail.name = "mono";
+                        break;
+                    case "hook":
+                    case "hookrightarrow":
+                        style.tail = { name: "hook", side: "top" };
+                        break;
+                    case "hook'":
+                        style.tail = { name: "hook", side: "bottom" };
+                        break;
+                    case "tail reversed":
+                    case "2tail reversed":
+                        style.tail.name = "arrowhead";
+                        break;
+
+                    // Head styles.
+                    case "no head":
+                    case "dash":
+                    case "-":
+                        style.head.name = "none";
+                        break;
+                    case "two heads":
+                    case "twoheadrightarrow":
+                        style.head.name = "epi";
+                        break;
+                    case "harpoon":
+                        style.head = { name: "harpoon", side: "top" };
+                        break;
+                    case "harpoon'":
+                        style.head = { name: "harpoon", side: "bottom" };
+                        break;
+
+                    // The default arrow style.
+                    case "to":
+                    case "rightarrow":
+                    case "->":
+                        break;
+
+                    default:
+                        // TikZ also permits colours to be given directly, e.g. `red`.
+                        const named_colour = value === null ? parse_colour(key, false) : null;
+                        if (named_colour !== null) {
+                            colour = label_colour = named_colour;
+                        } else {
+                            tikz_incompatibilities.add(`the \`${key}\` arrow option`);
+                        }
+                        break;
+                }
+            }
+
+            // Work out which label is the primary label, and whether any of the labels are actually
+            // decorations indicating the edge style, as quiver exports barred arrows, adjunctions,
+            // and pullbacks/pushouts.
+            let label = null;
+            for (const decoration of labels) {
+                if (decoration.label === "\\shortmid" && decoration.alignment === "over" && drawn) {
+                    style.body.name = "barred";
+                    continue;
+                }
+                if (!drawn) {
+                    const name = new Map([
+                        ["\\dashv", "adjunction"],
+                        ["\\lrcorner", "corner"],
+                        ["\\ulcorner", "corner-inverse"],
+                    ]).get(decoration.label);
+                    if (typeof name !== "undefined") {
+                        style.name = name;
+                        // quiver colours decorations using the label colour.
+                        colour = decoration.colour || colour;
+                        continue;
+                    }
+                }
+                if (decoration.label !== "") {
+                    if (label === null) {
+                        label = decoration;
+                    } else {
+                        tikz_incompatibilities.add("arrows with multiple labels");
+                    }
+                }
+            }
+
+            // Arrows without a style, which are not drawn, are invisible, except for their labels.
+            if (!drawn && style.name === "arrow") {
+                style.tail.name = style.body.name = style.head.name = "none";
+            }
+
+            if (source === null || target === null) {
+                tikz_incompatibilities.add("arrows with a source or target that was not found");
+                continue;
+            }
+            if (source === target) {
+                tikz_incompatibilities.add("loops");
+                continue;
+            }
+            if (Math.max(source.level, target.level) + 1 > CONSTANTS.MAXIMUM_CELL_LEVEL) {
+                tikz_incompatibilities.add(`cells of level greater than ${
+                    CONSTANTS.MAXIMUM_CELL_LEVEL
+                }`);
+                continue;
+            }
+
+            // The label alignment and position are only relevant if there is a label.
+            let [alignment, position] = ["left", 0.5];
+            if (label !== null) {
+                alignment = label.alignment || arrow_label.alignment || (phantom ? "over" : "left");
+                for (const value of [label.position, arrow_label.position]) {
+                    if (value !== null) {
+                        position = value;
+                        break;
+                    }
+                }
+            }
+
+            // Convert shortening into a proportion of the arrow length. We only know the length of
+            // the arrow in quiver approximately, from the positions of the vertices it connects.
+            const proportion = { source: 0, target: 0 };
+            if (shorten.source !== 0 || shorten.target !== 0) {
+                const displacement = source.is_vertex() && target.is_vertex() ?
+                    target.position.sub(source.position) : new Position(1, 0);
+                const angle = displacement.angle();
+                const multiplier = TIKZ_HORIZONTAL_MULTIPLIER * TIKZ_VERTICAL_MULTIPLIER
+                    / ((TIKZ_HORIZONTAL_MULTIPLIER ** 2 * Math.sin(angle) ** 2
+                    + TIKZ_VERTICAL_MULTIPLIER ** 2 * Math.cos(angle) ** 2) ** 0.5);
+                const length = displacement.length() * ui.default_cell_size * multiplier;
+                for (const end of ["source", "target"]) {
+                    proportion[end] = Math.max(0, Math.round(shorten[end] / length * 100));
+                }
+                // The arrow cannot be shortened by more than its length.
+                const total = proportion.source + proportion.target;
+                if (total > 100) {
+                    proportion.source = Math.floor(proportion.source / total * 100);
+                    proportion.target = 100 - proportion.source;
+                }
+            }
+
+            const properties = {
+                ...options,
+                label_alignment: alignment,
+                label_position: Math.max(0, Math.min(100, Math.round(position * 100))),
+                shorten: proportion,
+            };
+            if (colour !== null) {
+                properties.colour = colour;
+            }
+
+            const edge = new Edge(
+                ui,
+                label !== null ? label.label : "",
+                source,
+                target,
+                Edge.default_options(properties, style),
+                (label !== null && label.colour) || label_colour || Colour.black(),
+            );
+            for (const { name } of labels) {
+                if (name !== null) {
+                    references.set(name, edge);
+                }
+            }
+            cells.push(edge);
+        }
+
+        QuiverImportExport.complete_import(ui, cells);
+
+        return {
+            data: cells,
+            metadata: { tikz_incompatibilities },
+        };
+    }
+};
+
+QuiverImportExport.base64 = new class extends QuiverImportExport {
+    // The format we use for encoding quivers in base64 (primarily for link-sharing) is
+    // the following. This has been chosen based on minimality (for shorter representations),
+    // rather than readability.
+    //
+    // Note that an empty quiver has no representation.
+    //
+    // `[version: integer, |vertices|: integer, ...vertices, ...edges]`
+    //
+    // Parameters:
+    // - `version` is currently only permitted to be 0. The format has been designed to be
+    //   forwards-compatible with changes, so it is intended that this version will not
+    //   change.
+    // - `|vertices|` is the length of the array `vertices`.
+    // - `vertices` is an array of vertices of the form:
+    //      `[x: integer, y: integer, label: string, label_colour: [h, s, l, a]]`
+    //      + `label` is optional (if not present, it will default to `""`), though it must be
+    //         present if any later option is.
+    //      + `label_colour` is optional (if not present, it will default to `[0, 0, 0, 1]`).
+    //          + `h` is an integer from `0` to `360`
+    //          + `s` is an integer from `0` to `100`
+    //          + `l` is an integer from `0` to `100`
+    //          + `a` is a floating-point number from `0` to `1`
+    // - `edges` is an array of edges of the form:
+    //      `[source: index, target: index, label: string, alignment, options, label_colour]`
+    //      + (label) `alignment` is an enum comprising the following options:
+    //          * `0`: left
+    //          * `1`: centre
+    //          * `2`: right
+    //          * `3`: over
+    //        It has been distinguished from the other options as one that is frequently
+    //        changed from the default, to avoid the overhead of encoding an options
+    //        object.
+    //      + `options` is an object containing the delta of the options from the defaults.
+    //         This is the only parameter that is not encoded simply as an array, as the
+    //         most likely parameter to be changed in the future.
+    //      + `label_colour` is stored in the same manner as for vertices.
+    //
+    // Notes:
+    // - An `index` is an integer indexing into the array `[...vertices, ...edges]`.
+    // - Arrays may be truncated if the values of the elements are the default values.
+
+    export(quiver, _, options) {
+        // Remove the query string from the current URL and use that as a base.
+        const URL_prefix = window.location.href.replace(/\?.*$/, "");
+
+        if (quiver.is_empty()) {
+            // No need to have an encoding of an empty quiver;
+            // we'll just use the URL directly.
+            return {
+                data: URL_prefix,
+                metadata: {},
+            };
+        }
+
+        const cells = [];
+        const indices = new Map();
+
+        let offset = new Position(Infinity, Infinity);
+        // We want to ensure that the top-left cell is in position (0, 0), so we need
+        // to work out where the top-left cell actually is, to compute an offset.
+        for (const vertex of quiver.cells[0]) {
+            offset = offset.min(vertex.position);
+        }
+        for (const vertex of quiver.cells[0]) {
+            const { label, label_colour } = vertex;
+            indices.set(vertex, cells.length);
+            const position = vertex.position.sub(offset).toArray();
+            const cell = [...position];
+            // In the name of efficiency, we omit any parameter that is not necessary, and for which
+            // no later parameter is necessary.
+            if (label !== "") {
+                cell.push(label);
+            }
+            if (label !== "" && label_colour.is_not_black()) {
+                // Even if the colour is not black, it's irrelevant if there is no label.
+                cell.push(label_colour.hsla());
+            }
+            cells.push(cell);
+        }
+
+        for (let level = 1; level < quiver.cells.length; ++level) {
+            for (const edge of quiver.cells[level]) {
+                const { label, label_colour, options: { label_alignment, ...options } } = edge;
+                const [source, target] = [indices.get(edge.source), indices.get(edge.target)];
+                indices.set(edge, cells.length);
+                const cell = [source, target];
+                // We want to omit parameters that are unnecessary (i.e. have the default
+                // values). However, because we store parameters in an array, the only way
+                // we can distinguish missing values is by the length. Therefore, we can
+                // only truncate the array (not omit elements partway through the array).
+                // This means we may need to include unnecessary information if there is a
+                // non-default parameter after a default one. The parameters most likely to
+                // be default are placed further back in the array to reduce the frequency
+                // of this situation.
+                const end = [];
+
+                // Even if the colour is not black, it's irrelevant if there is no label.
+                if (label !== "" && label_colour.is_not_black()) {
+                    end.push(label_colour.hsla());
+                }
+
+                // We compute a delta of the edge options compared
+                // to the default, so we encode a minimum of data.
+                const default_options = Edge.default_options({ level });
+
+                const delta = QuiverExport.delta(options, default_options);
+                if (end.length > 0 || Object.keys(delta).length > 0) {
+                    end.push(delta);
+                }
+
+                const push_if_necessary = (parameter, default_value, condition = true) => {
+                    if (end.length > 0 || (parameter !== default_value && condition)) {
+                        end.push(parameter);
+                    }
+                };
+
+                const variant = { left: 0, centre: 1, right: 2, over: 3 }[label_alignment];
+                // It's only necessary to encode the label alignment if the label is not blank.
+                push_if_necessary(variant, 0, label !== "");
                 push_if_necessary(label, "");
 
                 cell.push(...end.reverse());
@@ -1069,23 +1884,7 @@ QuiverImportExport.base64 = new class extends QuiverImportExport {
         ui.reposition_focus_point(ui.position_from_offset(ui.view.sub(Point.diag(0.5))));
         delay(() => ui.focus_point.class_list.add("smooth"));
 
-        // When cells are created, they are usually queued. We don't want any cells that have been
-        // imported to be queued.
-        for (const cell of indices) {
-            cell.element.query_selector("kbd.queue").class_list.remove("queue");
-        }
-
-        // Update all the affected columns and rows.
-        delay(() => ui.update_col_row_size(
-            ...indices.filter((cell) => cell.is_vertex()).map((vertex) => vertex.position)
-        ));
-
-        // Stop buffering updates, so that individual changes to cells will resize the grid.
-        ui.buffer_updates = false;
-
-        // If the quiver is now nonempty, some toolbar actions will be available.
-        ui.toolbar.update(ui);
-        ui.update_focus_tooltip();
+        QuiverImportExport.complete_import(ui, indices);
 
         if (errors.length > 0) {
             // Just throw the first error.
@@ -1118,3 +1917,2580 @@ style="border-radius: 8px; border: none;">\
         };
     }
 }
+
+QuiverImportExport.dot = new class extends QuiverImportExport {
+    /// Exports the diagram as a Graphviz DOT graph. Vertices are pinned to their grid positions,
+    /// so that the layout is preserved when the graph is rendered with `neato -n`. Labels are kept
+    /// as LaTeX, delimited by `$...$` (as for DOT import), unless the `export.dot.strip_math`
+    /// setting is enabled, in which case we convert them to plain text. Features that cannot be
+    /// represented in DOT are reported in `metadata.dot_incompatibilities`.
+    export(quiver, settings, options, definitions) {
+        const dot_incompatibilities = new Set();
+
+        // The distance between adjacent grid cells, in points.
+        const GRID_SIZE = 72;
+
+        // Quotes a string for DOT. Backslashes introduce escape sequences in DOT labels, so they
+        // must themselves be escaped.
+        const quote = (string) => `"${string.replace(/["\\]/g, (c) => `\\${c}`)}"`;
+
+        // A selection of common LaTeX commands that have a reasonable plain text equivalent.
+        const SYMBOLS = new Map([
+            ["alpha", "α"], ["beta", "β"], ["gamma", "γ"], ["delta", "δ"], ["epsilon", "ε"],
+            ["varepsilon", "ε"], ["zeta", "ζ"], ["eta", "η"], ["theta", "θ"], ["iota", "ι"],
+            ["kappa", "κ"], ["lambda", "λ"], ["mu", "μ"], ["nu", "ν"], ["xi", "ξ"], ["pi", "π"],
+            ["rho", "ρ"], ["sigma", "σ"], ["tau", "τ"], ["upsilon", "υ"], ["phi", "φ"],
+            ["varphi", "φ"], ["chi", "χ"], ["psi", "ψ"], ["omega", "ω"], ["Gamma", "Γ"],
+            ["Delta", "Δ"], ["Theta", "Θ"], ["Lambda", "Λ"], ["Xi", "Ξ"], ["Pi", "Π"],
+            ["Sigma", "Σ"], ["Phi", "Φ"], ["Psi", "Ψ"], ["Omega", "Ω"], ["to", "→"],
+            ["rightarrow", "→"], ["leftarrow", "←"], ["Rightarrow", "⇒"], ["times", "×"],
+            ["otimes", "⊗"], ["oplus", "⊕"], ["circ", "∘"], ["cdot", "·"], ["bullet", "•"],
+            ["infty", "∞"], ["dots", "…"], ["ldots", "…"], ["cdots", "⋯"], ["prime", "′"],
+            ["dashv", "⊣"], ["vdash", "⊢"], ["cong", "≅"], ["simeq", "≃"], ["leq", "≤"],
+            ["geq", "≥"], ["in", "∈"], ["subseteq", "⊆"], ["cup", "∪"], ["cap", "∩"],
+            ["emptyset", "∅"], ["ell", "ℓ"], ["textbackslash", "\\"], ["textasciicircum", "^"],
+            ["textasciitilde", "~"], ["quad", " "], ["qquad", " "],
+        ]);
+
+        // Commands with two arguments, which do not have a plain text equivalent.
+        const BINARY = /\\(?:[dt]?binom|overset|underset|stackrel)(?![a-zA-Z])/;
+
+        // Converts a LaTeX label into a DOT label.
+        const convert_label = (label) => {
+            if (!settings.get("export.dot.strip_math")) {
+                return label.trim() !== "" ? `$${label}$` : "";
+            }
+            const binary = label.match(BINARY);
+            if (binary !== null) {
+                // Concatenating the arguments would change the meaning of the label, so we leave
+                // it as it is.
+                dot_incompatibilities.add(`the \`${binary[0]}\` command in plain text labels`);
+                return label;
+            }
+            const fraction = convert_fractions(label);
+            if (fraction === null) {
+                dot_incompatibilities.add("malformed fractions in plain text labels");
+                return label;
+            }
+            return fraction
+                // Escaped symbols.
+                .replace(/\\([{}$&#%_ ,;!])/g, (_, c) => [",", ";", "!"].includes(c) ? " " : c)
+                // Commands, which are replaced with a symbol if possible. Otherwise, commands
+                // taking arguments (like `\mathbf{X}`) are dropped, keeping their arguments.
+                .replace(/\\([a-zA-Z]+)(\s*\{)?/g, (_, name, argument) => {
+                    return SYMBOLS.get(name) || (typeof argument !== "undefined" ? "" : name);
+                })
+                .replace(/[{}$]/g, "")
+                .replace(/\s+/g, " ")
+                .trim();
+        };
+
+        // Converts fractions `\frac{x}{y}` in a LaTeX label into `x/y`, bracketing the numerator
+        // and denominator unless they are single words. Returns `null` if a fraction is missing
+        // an argument.
+        const convert_fractions = (label) => {
+            const FRACTION = /\\[dt]?frac(?![a-zA-Z])/g;
+            let output = "";
+            let i = 0;
+            for (let match; (match = FRACTION.exec(label)) !== null;) {
+                output += label.slice(i, match.index);
+                i = FRACTION.lastIndex;
+                const parts = [];
+                while (parts.length < 2) {
+                    while (/\s/.test(label[i])) {
+                        ++i;
+                    }
+                    let part;
+                    if (label[i] === "{") {
+                        let depth = 0;
+                        let end = i;
+                        do {
+                            depth += label[end] === "{" ? 1 : label[end] === "}" ? -1 : 0;
+                            end += label[end] === "\\" ? 2 : 1;
+                        } while (depth > 0 && end < label.length);
+                        if (depth > 0) {
+                            return null;
+                        }
+                        part = label.slice(i + 1, end - 1);
+                        i = end;
+                    } else {
+                        // Single-token arguments, as in `\frac12` or `\frac\pi2`.
+                        part = (label.slice(i).match(/^(?:\\[a-zA-Z]+|\\.|[^{}])/) || [null])[0];
+                        if (part === null) {
+                            return null;
+                        }
+                        i += part.length;
+                    }
+                    const converted = convert_label(part);
+                    parts.push(/^[\p{L}\p{N}.′]*$/u.test(converted) ? converted : `(${converted})`);
+                }
+                output += ` ${parts.join("/")} `;
+                FRACTION.lastIndex = i;
+            }
+            return output + label.slice(i);
+        };
+
+        // Formats a list of attributes, omitting those that are `null`.
+        const attributes = (attributes) => {
+            const list = Object.entries(attributes).filter(([, value]) => value !== null)
+                .map(([key, value]) => `${key}=${quote(`${value}`)}`);
+            return list.length > 0 ? ` [${list.join(", ")}]` : "";
+        };
+
+        const lines = [];
+
+        // Output the vertices. DOT uses a y-axis that points upwards, so we flip the
+        // y-coordinates.
+        const names = new Map();
+        for (const vertex of quiver.cells[0]) {
+            const name = `v${names.size}`;
+            names.set(vertex, name);
+            lines.push(`${name}${attributes({
+                label: convert_label(vertex.label),
+                pos: `${vertex.position.x * GRID_SIZE},${-vertex.position.y * GRID_SIZE}!`,
+                fontcolor: vertex.label_colour.is_not_black() ? vertex.label_colour.hex() : null,
+            })};`);
+        }
+
+        // Output the edges. DOT only permits edges between nodes, so we cannot export edges
+        // between edges.
+        for (let level = 1; level < quiver.cells.length; ++level) {
+            for (const edge of quiver.cells[level]) {
+                if (!edge.source.is_vertex() || !edge.target.is_vertex()) {
+                    dot_incompatibilities.add("arrows between arrows");
+                    continue;
+                }
+                if (edge.options.style.name !== "arrow") {
+                    dot_incompatibilities.add(QuiverExport.unsupported_style(edge));
+                    continue;
+                }
+
+                const parameters = {
+                    label: edge.label !== "" ? convert_label(edge.label) : null,
+                    fontcolor: edge.label_colour.is_not_black() ? edge.label_colour.hex() : null,
+                };
+                const styles = [];
+                let colour = edge.options.colour.hex();
+                // Multiple lines may be drawn in DOT using a colour list, in which the colours
+                // are separated by `invis`.
+                if (edge.options.level > 1) {
+                    colour = new Array(edge.options.level).fill(colour).join(":invis:");
+                }
+                if (edge.options.level > 1 || edge.options.colour.is_not_black()) {
+                    parameters.color = colour;
+                }
+
+                const { tail, body, head } = edge.options.style;
+                switch (body.name) {
+                    case "dashed":
+                    case "dotted":
+                        styles.push(body.name);
+                        break;
+                    case "squiggly":
+                        dot_incompatibilities.add("squiggly arrows");
+                        break;
+                    case "barred":
+                        dot_incompatibilities.add("barred arrows");
+                        break;
+                    case "none":
+                        if (head.name === "none" && tail.name === "none") {
+                            styles.push("invis");
+                        } else {
+                            dot_incompatibilities.add("arrows without bodies");
+                        }
+                        break;
+                }
+                if (styles.length > 0) {
+                    parameters.style = styles.join(",");
+                }
+
+                switch (head.name) {
+                    case "none":
+                        parameters.arrowhead = "none";
+                        break;
+                    case "epi":
+                        parameters.arrowhead = "normalnormal";
+                        break;
+                    case "harpoon":
+                        parameters.arrowhead = head.side === "top" ? "lnormal" : "rnormal";
+                        break;
+                }
+
+                // Arrow tails are only drawn in DOT when `dir` is `both`.
+                if (tail.name !== "none") {
+                    parameters.dir = "both";
+                    switch (tail.name) {
+                        case "maps to":
+                            parameters.arrowtail = "tee";
+                            break;
+                        case "mono":
+                            parameters.arrowtail = "inv";
+                            break;
+                        case "hook":
+                            parameters.arrowtail = tail.side === "top" ? "lcurve" : "rcurve";
+                            break;
+                        case "arrowhead":
+                            parameters.arrowtail = "normal";
+                            break;
+                    }
+                }
+
+                if (edge.options.curve !== 0) {
+                    dot_incompatibilities.add("curved arrows");
+                }
+                if (edge.options.offset !== 0) {
+                    dot_incompatibilities.add("offset arrows");
+                }
+                if (edge.options.shorten.source !== 0 || edge.options.shorten.target !== 0) {
+                    dot_incompatibilities.add("shortened arrows");
+                }
+
+                const [source, target] = [names.get(edge.source), names.get(edge.target)];
+                lines.push(`${source} -> ${target}${attributes(parameters)};`);
+            }
+        }
+
+        return {
+            data: `// ${
+                QuiverImportExport.base64.export(quiver, settings, options, definitions).data
+            }\ndigraph {\n${
+                // Vertices are drawn as their labels alone, as in quiver.
+                ["node [shape=plaintext];", ...lines].map((line) => `\t${line}`).join("\n")
+            }\n}`,
+            metadata: { dot_incompatibilities },
+        };
+    }
+
+    /// Imports a Graphviz DOT graph into the existing diagram, returning the new cells (as `data`).
+    /// Unless every node is pinned by `pos`, we lay out the graph in layers, as `dot` does.
+    /// Attributes that cannot be represented in quiver are reported in
+    /// `metadata.dot_incompatibilities`.
+    import(ui, string) {
+        const dot_incompatibilities = new Set();
+
+        // Split the graph into tokens. A token is either an identifier (which may be quoted, or
+        // an HTML string), or one of the punctuation symbols in DOT.
+        const tokens = [];
+        const WHITESPACE = /(?:\s+|\/\/[^\n]*|\/\*[^]*?\*\/|^#[^\n]*)+/my;
+        const IDENTIFIER =
+            /[a-zA-Z\u0080-\uffff_][a-zA-Z\u0080-\uffff_0-9]*|-?(?:\.\d+|\d+\.?\d*)/y;
+        const QUOTED = /"((?:[^"\\]|\\[^])*)"/y;
+        const PUNCTUATION = /->|--|[{}[\]=;,:+]/y;
+        let i = 0;
+        // Returns the match for `regex` at index `i`, advancing `i` past the match if there is one.
+        const consume = (regex) => {
+            regex.lastIndex = i;
+            const match = regex.exec(string);
+            if (match !== null) {
+                i = regex.lastIndex;
+            }
+            return match;
+        };
+        while (consume(WHITESPACE), i < string.length) {
+            let match;
+            if ((match = consume(QUOTED)) !== null) {
+                // Quoted strings may be concatenated using `+`. The only escape sequence that is
+                // interpreted in all strings is `\"`: the others depend on the attribute.
+                const value = match[1].replace(/\\"/g, "\"");
+                const previous = tokens[tokens.length - 1];
+                if (tokens.length >= 2 && previous.value === "+" && !previous.identifier
+                    && tokens[tokens.length - 2].quoted) {
+                    tokens.pop();
+                    tokens[tokens.length - 1].value += value;
+                } else {
+                    tokens.push({ value, identifier: true, quoted: true });
+                }
+            } else if (string[i] === "<") {
+                // HTML strings are delimited by balanced angle brackets.
+                let [end, depth] = [i, 0];
+                do {
+                    if (string[end] === "<") {
+                        ++depth;
+                    } else if (string[end] === ">") {
+                        --depth;
+                    }
+                    ++end;
+                } while (depth > 0 && end < string.length);
+                if (depth > 0) {
+                    throw new Error("unmatched `<`");
+                }
+                tokens.push({ value: string.slice(i + 1, end - 1), identifier: true, html: true });
+                i = end;
+            } else if ((match = consume(PUNCTUATION)) !== null) {
+                tokens.push({ value: match[0], identifier: false });
+            } else if ((match = consume(IDENTIFIER)) !== null) {
+                tokens.push({ value: match[0], identifier: true });
+            } else {
+                throw new Error(`unexpected character \`${string[i]}\``);
+            }
+        }
+
+        // Helper functions for parsing.
+
+        let position = 0;
+        const peek = (offset = 0) => tokens[position + offset] || null;
+        // Returns whether the next token is the punctuation (or keyword) `value`.
+        const next_is = (value, offset = 0) => {
+            const token = peek(offset);
+            return token !== null && !token.quoted && !token.html && (token.identifier
+                ? token.value.toLowerCase() === value : token.value === value);
+        };
+        const expect = (value) => {
+            if (!next_is(value)) {
+                const token = peek();
+                throw new Error(`expected \`${value}\`, but found ${
+                    token !== null ? `\`${token.value}\`` : "the end of the graph"
+                }`);
+            }
+            ++position;
+        };
+        const identifier = () => {
+            const token = peek();
+            if (token === null || !token.identifier) {
+                throw new Error(`expected an identifier, but found ${
+                    token !== null ? `\`${token.value}\`` : "the end of the graph"
+                }`);
+            }
+            ++position;
+            return token;
+        };
+
+        // The graph header.
+        if (next_is("strict")) {
+            ++position;
+        }
+        let directed;
+        if (next_is("digraph")) {
+            directed = true;
+        } else if (next_is("graph")) {
+            directed = false;
+        } else {
+            throw new Error("expected `graph` or `digraph`");
+        }
+        ++position;
+        const graph_name = !next_is("{") ? identifier().value : "";
+
+        // The nodes in the graph, in order of their first appearance, mapping node IDs to their
+        // attributes.
+        const nodes = new Map();
+        const edges = [];
+        const graph_attributes = new Map();
+
+        // Adds the node `id` to the graph, if it has not already been added.
+        const add_node = (id, defaults) => {
+            if (!nodes.has(id)) {
+                nodes.set(id, new Map(defaults));
+            }
+        };
+
+        // Parses a sequence of attribute lists, e.g. `[a=b, c=d][e=f]`.
+        const attribute_list = () => {
+            const attributes = new Map();
+            while (next_is("[")) {
+                ++position;
+                while (!next_is("]")) {
+                    const key = identifier().value;
+                    expect("=");
+                    attributes.set(key, identifier());
+                    if (next_is(",") || next_is(";")) {
+                        ++position;
+                    }
+                }
+                expect("]");
+            }
+            return attributes;
+        };
+
+        // Parses a node ID, ignoring any port.
+        const node_id = () => {
+            const id = identifier().value;
+            if (next_is(":")) {
+                dot_incompatibilities.add("ports");
+                ++position;
+                identifier();
+                if (next_is(":")) {
+                    ++position;
+                    identifier();
+                }
+            }
+            return id;
+        };
+
+        // Parses a list of statements, returning the set of nodes that they mention. Node and edge
+        // attribute defaults are scoped to the (sub)graph in which they are declared.
+        const statement_list = (node_defaults, edge_defaults) => {
+            [node_defaults, edge_defaults] = [new Map(node_defaults), new Map(edge_defaults)];
+            const mentioned = new Set();
+
+            // Parses a node or subgraph, which may be used as an endpoint of an edge.
+            const endpoint = () => {
+                if (next_is("subgraph") || next_is("{")) {
+                    return subgraph();
+                }
+                const id = node_id();
+                add_node(id, node_defaults);
+                mentioned.add(id);
+                return new Set([id]);
+            };
+
+            const subgraph = () => {
+                if (next_is("subgraph")) {
+                    ++position;
+                    if (!next_is("{")) {
+                        identifier();
+                    }
+                }
+                expect("{");
+                const nodes = statement_list(node_defaults, edge_defaults);
+                expect("}");
+                for (const node of nodes) {
+                    mentioned.add(node);
+                }
+                return nodes;
+            };
+
+            while (peek() !== null && !next_is("}")) {
+                if ((next_is("graph") || next_is("node") || next_is("edge")) && next_is("[", 1)) {
+                    const kind = peek().value.toLowerCase();
+                    ++position;
+                    const attributes = attribute_list();
+                    const defaults = {
+                        graph: graph_attributes,
+                        node: node_defaults,
+                        edge: edge_defaults,
+                    }[kind];
+                    for (const [key, value] of attributes) {
+                        defaults.set(key, value);
+                    }
+                } else if (peek().identifier && next_is("=", 1)) {
+                    // A graph attribute, e.g. `rankdir=LR`.
+                    const key = identifier().value;
+                    ++position;
+                    graph_attributes.set(key, identifier());
+                } else {
+                    // A node or edge statement.
+                    const endpoints = [endpoint()];
+                    while (next_is("->") || next_is("--")) {
+                        ++position;
+                        endpoints.push(endpoint());
+                    }
+                    const attributes = attribute_list();
+                    if (endpoints.length === 1) {
+                        for (const id of endpoints[0]) {
+                            for (const [key, value] of attributes) {
+                                nodes.get(id).set(key, value);
+                            }
+                        }
+                    } else {
+                        for (let j = 1; j < endpoints.length; ++j) {
+                            for (const source of endpoints[j - 1]) {
+                                for (const target of endpoints[j]) {
+                                    edges.push({
+                                        source,
+                                        target,
+                                        attributes: new Map([...edge_defaults, ...attributes]),
+                                    });
+                                }
+                            }
+                        }
+                    }
+                }
+                if (next_is(";")) {
+                    ++position;
+                }
+            }
+
+            return mentioned;
+        };
+
+        expect("{");
+        statement_list(new Map(), new Map());
+        expect("}");
+        if (peek() !== null) {
+            dot_incompatibilities.add("multiple graphs");
+        }
+
+        // Helper functions for converting attributes.
+
+        // Converts a DOT label into a quiver label. Labels are treated as text, unless they are
+        // delimited by `$...$`, in which case they are treated as (LaTeX) mathematics. `escapes`
+        // describes the substitutions for escape sequences such as `\N` (the node name).
+        const convert_label = (token, escapes) => {
+            if (token.html) {
+                dot_incompatibilities.add("HTML labels");
+                // We keep only the text content of HTML labels.
+                token = { value: token.value.replace(/<[^>]*>/g, " ") };
+            }
+            let label = token.value.replace(/\\([NGETHnlr\\])/g, (sequence, escape) => {
+                if (escape === "\\") {
+                    // `\\` is a literal backslash.
+                    return escape;
+                }
+                return escapes.hasOwnProperty(escape) ? escapes[escape] : " ";
+            }).replace(/\s+/g, " ").trim();
+            const math = label.match(/^\$(.*)\$$/);
+            if (math !== null) {
+                return math[1].trim();
+            }
+            if (label === "") {
+                return "";
+            }
+            const ESCAPES = new Map([
+                ["\\", "\\textbackslash "], ["{", "\\{"], ["}", "\\}"], ["$", "\\$"], ["&", "\\&"],
+                ["#", "\\#"], ["%", "\\%"], ["_", "\\_"], ["^", "\\textasciicircum "],
+                ["~", "\\textasciitilde "],
+            ]);
+            return `\\text{${label.replace(/[\\{}$&#%_^~]/g, (c) => ESCAPES.get(c))}}`;
+        };
+
+        // Converts a DOT colour, which may be given by name, as `#rrggbb(aa)`, or as HSV values.
+        // Returns `null` if the colour is not recognised.
+        const convert_colour = (value) => {
+            if (value.includes(":")) {
+                dot_incompatibilities.add("colour lists");
+                value = value.split(":")[0];
+            }
+            const hex = value.match(/^#([0-9a-f]{2})([0-9a-f]{2})([0-9a-f]{2})([0-9a-f]{2})?$/i);
+            if (hex !== null) {
+                return Colour.from_rgba(...hex.slice(1, 4).map((x) => parseInt(x, 16)));
+            }
+            const hsv = value.match(/^([\d.]+)[,\s]+([\d.]+)[,\s]+([\d.]+)$/);
+            if (hsv !== null) {
+                // Convert from HSV to HSL.
+                const [h, s, v] = hsv.slice(1).map((x) => Math.min(parseFloat(x), 1));
+                const l = v * (1 - s / 2);
+                const saturation = l === 0 || l === 1 ? 0 : (v - l) / Math.min(l, 1 - l);
+                return new Colour(
+                    ...[h * 360, saturation * 100, l * 100].map((x) => Math.round(x)),
+                );
+            }
+            // A selection of the most common X11 colour names.
+            const COLOURS = new Map([
+                ["black", [0, 0, 0]], ["white", [255, 255, 255]], ["red", [255, 0, 0]],
+                ["green", [0, 255, 0]], ["blue", [0, 0, 255]], ["yellow", [255, 255, 0]],
+                ["cyan", [0, 255, 255]], ["magenta", [255, 0, 255]], ["orange", [255, 165, 0]],
+                ["purple", [160, 32, 240]], ["gray", [190, 190, 190]], ["grey", [190, 190, 190]],
+                ["brown", [165, 42, 42]], ["pink", [255, 192, 203]],
+            ]);
+            if (COLOURS.has(value.toLowerCase())) {
+                return Colour.from_rgba(...COLOURS.get(value.toLowerCase()));
+            }
+            dot_incompatibilities.add(`the colour \`${value}\``);
+            return null;
+        };
+
+        // Converts a DOT arrow shape into a quiver head or tail style.
+        const convert_arrow = (value, end) => {
+            switch (value) {
+                case "normal":
+                case "vee":
+                case "open":
+                    return { name: "arrowhead" };
+                case "none":
+                    return { name: "none" };
+                case "normalnormal":
+                case "veevee":
+                    if (end === "head") {
+                        return { name: "epi" };
+                    }
+                    break;
+                case "tee":
+                    if (end === "tail") {
+                        return { name: "maps to" };
+                    }
+                    break;
+                case "inv":
+                    if (end === "tail") {
+                        return { name: "mono" };
+                    }
+                    break;
+                case "lcurve":
+                case "rcurve":
+                    if (end === "tail") {
+                        return { name: "hook", side: value === "lcurve" ? "top" : "bottom" };
+                    }
+                    break;
+                case "lnormal":
+                case "rnormal":
+                    if (end === "head") {
+                        return { name: "harpoon", side: value === "lnormal" ? "top" : "bottom" };
+                    }
+                    break;
+            }
+            dot_incompatibilities.add(`the \`${value}\` arrow shape`);
+            return { name: end === "head" ? "arrowhead" : "none" };
+        };
+
+        // The distance between adjacent grid cells, in points, as in `export`.
+        const GRID_SIZE = 72;
+
+        // Lay out the graph. First we assign each node a layer, so that edges point from earlier
+        // layers to later layers whenever possible. Edges that would form a cycle are ignored for
+        // this purpose.
+        const successors = new Map(Array.from(nodes.keys()).map((id) => [id, []]));
+        for (const { source, target } of edges) {
+            if (source !== target) {
+                successors.get(source).push(target);
+            }
+        }
+        const visited = new Map();
+        // The nodes in reverse topological order (ignoring the edges that form cycles).
+        const order = [];
+        const back_edges = new Set();
+        for (const root of nodes.keys()) {
+            if (visited.has(root)) {
+                continue;
+            }
+            // We use an explicit stack, as graphs can be large enough to exhaust the call stack.
+            const stack = [[root, 0]];
+            visited.set(root, "active");
+            while (stack.length > 0) {
+                const top = stack[stack.length - 1];
+                const [id, index] = top;
+                if (index < successors.get(id).length) {
+                    ++top[1];
+                    const next = successors.get(id)[index];
+                    if (!visited.has(next)) {
+                        visited.set(next, "active");
+                        stack.push([next, 0]);
+                    } else if (visited.get(next) === "active") {
+                        back_edges.add(`${id} ${next}`);
+                    }
+                } else {
+                    visited.set(id, "done");
+                    order.push(id);
+                    stack.pop();
+                }
+            }
+        }
+        const layer = new Map(Array.from(nodes.keys()).map((id) => [id, 0]));
+        const has_predecessor = new Set();
+        for (const id of order.reverse()) {
+            for (const next of successors.get(id)) {
+                if (!back_edges.has(`${id} ${next}`)) {
+                    layer.set(next, Math.max(layer.get(next), layer.get(id) + 1));
+                    has_predecessor.add(next);
+                }
+            }
+        }
+        // Nodes with no predecessors are placed in the first layer by the above, but it is neater
+        // to place them immediately before their earliest successor.
+        for (const [id, nexts] of successors) {
+            const forward = nexts.filter((next) => !back_edges.has(`${id} ${next}`));
+            if (!has_predecessor.has(id) && forward.length > 0) {
+                layer.set(id, Math.min(...forward.map((next) => layer.get(next))) - 1);
+            }
+        }
+
+        // Then we order the nodes within each layer, to reduce crossings, by repeatedly sorting
+        // each layer by the average index of its neighbours in the adjacent layer.
+        const layers = [];
+        for (const id of nodes.keys()) {
+            while (layers.length <= layer.get(id)) {
+                layers.push([]);
+            }
+            layers[layer.get(id)].push(id);
+        }
+        const neighbours = new Map(Array.from(nodes.keys()).map((id) => [id, []]));
+        for (const { source, target } of edges) {
+            if (source !== target) {
+                neighbours.get(source).push(target);
+                neighbours.get(target).push(source);
+            }
+        }
+        const index = new Map();
+        const reindex = (nodes) => nodes.forEach((id, i) => index.set(id, i));
+        layers.forEach(reindex);
+        const SWEEPS = 4;
+        for (let sweep = 0; sweep < SWEEPS; ++sweep) {
+            // Alternate between sweeping downwards and upwards.
+            const downwards = sweep % 2 === 0;
+            for (let k = 1; k < layers.length; ++k) {
+                const current = downwards ? k : layers.length - 1 - k;
+                const adjacent = downwards ? current - 1 : current + 1;
+                const barycentre = new Map(layers[current].map((id) => {
+                    const adjacent_neighbours = neighbours.get(id)
+                        .filter((neighbour) => layer.get(neighbour) === adjacent);
+                    return [id, adjacent_neighbours.length > 0
+                        ? adjacent_neighbours.reduce((sum, neighbour) => {
+                            return sum + index.get(neighbour);
+                        }, 0) / adjacent_neighbours.length
+                        : index.get(id)];
+                }));
+                layers[current].sort((a, b) => barycentre.get(a) - barycentre.get(b));
+                reindex(layers[current]);
+            }
+        }
+
+        // Finally, we assign grid positions, centring each layer. `rankdir` determines the
+        // direction in which the layers progress.
+        const rankdir = graph_attributes.has("rankdir")
+            ? graph_attributes.get("rankdir").value.toUpperCase() : "TB";
+        if (!["TB", "BT", "LR", "RL"].includes(rankdir)) {
+            dot_incompatibilities.add(`the \`rankdir\` value \`${rankdir}\``);
+        }
+        const width = Math.max(...layers.map((nodes) => nodes.length));
+        const relative_positions = new Map();
+        layers.forEach((nodes, depth) => {
+            const indent = Math.floor((width - nodes.length) / 2);
+            if (rankdir === "BT" || rankdir === "RL") {
+                depth = layers.length - 1 - depth;
+            }
+            nodes.forEach((id, i) => {
+                relative_positions.set(id, rankdir === "LR" || rankdir === "RL"
+                    ? new Position(depth, indent + i) : new Position(indent + i, depth));
+            });
+        });
+
+        // If every node is pinned by `pos` (as in graphs exported by quiver), we use those
+        // positions instead, as long as no two nodes are snapped to the same grid cell. DOT uses
+        // a y-axis that points upwards, so we flip the y-coordinates.
+        const pins = new Map();
+        for (const [id, attributes] of nodes) {
+            const pos = attributes.has("pos") ? attributes.get("pos").value
+                .match(/^\s*(-?[\d.]+)\s*,\s*(-?[\d.]+)\s*!?\s*$/) : null;
+            if (pos !== null) {
+                pins.set(id, new Position(
+                    Math.round(parseFloat(pos[1]) / GRID_SIZE),
+                    Math.round(-parseFloat(pos[2]) / GRID_SIZE),
+                ));
+            }
+        }
+        const pinned = pins.size > 0 && pins.size === nodes.size
+            && new Set(Array.from(pins.values(), (pin) => `${pin}`)).size === pins.size;
+        if (pinned) {
+            let min = new Position(Infinity, Infinity);
+            for (const pin of pins.values()) {
+                min = min.min(pin);
+            }
+            for (const [id, pin] of pins) {
+                relative_positions.set(id, pin.sub(min));
+            }
+        }
+
+        for (const key of graph_attributes.keys()) {
+            if (key !== "rankdir") {
+                dot_incompatibilities.add(`the \`${key}\` graph attribute`);
+            }
+        }
+
+        const origin = QuiverImportExport.import_origin(
+            ui,
+            Array.from(relative_positions.values()),
+        );
+
+        // We don't want to relayout every time we add a new cell: instead, we should perform
+        // layout once, once all of the cells have been created.
+        ui.buffer_updates = true;
+
+        const cells = [];
+        const vertices = new Map();
+        for (const [id, attributes] of nodes) {
+            let [label, label_colour] = [
+                convert_label({ value: id }, { N: id, G: graph_name }),
+                Colour.black(),
+            ];
+            for (const [key, value] of attributes) {
+                switch (key) {
+                    case "label":
+                        label = convert_label(value, { N: id, G: graph_name });
+                        break;
+                    case "fontcolor":
+                        label_colour = convert_colour(value.value) || label_colour;
+                        break;
+                    case "pos":
+                        // Positions are only used if every node is pinned.
+                        if (!pinned) {
+                            dot_incompatibilities.add("the `pos` node attribute");
+                        }
+                        break;
+                    case "shape":
+                        // quiver never draws a shape around its vertices.
+                        if (!["plaintext", "plain", "none"].includes(value.value)) {
+                            dot_incompatibilities.add(`the \`${value.value}\` node shape`);
+                        }
+                        break;
+                    default:
+                        dot_incompatibilities.add(`the \`${key}\` node attribute`);
+                        break;
+                }
+            }
+            const vertex = new Vertex(
+                ui,
+                label,
+                origin.add(relative_positions.get(id)),
+                label_colour,
+            );
+            vertices.set(id, vertex);
+            cells.push(vertex);
+        }
+
+        // Edges between the same pair of nodes are spread out using curves, so that they do not
+        // overlap. We key the groups of edges by their (unordered) endpoints.
+        const parallel = new Map();
+        const endpoints_key = ({ source, target }) => JSON.stringify([source, target].sort());
+        for (const edge of edges) {
+            const key = endpoints_key(edge);
+            parallel.set(key, (parallel.get(key) || 0) + 1);
+        }
+        const seen = new Map();
+
+        // Returns whether the straight line between two vertices passes through another vertex,
+        // in which case we need to curve the edge to avoid it.
+        const passes_through_vertex = (source, target) => {
+            const difference = target.sub(source);
+            const gcd = (a, b) => b === 0 ? a : gcd(b, a % b);
+            const steps = gcd(Math.abs(difference.x), Math.abs(difference.y));
+            for (let step = 1; step < steps; ++step) {
+                const point = source.add(difference.div(steps).mul(step));
+                if (ui.positions.has(`${point}`)) {
+                    return true;
+                }
+            }
+            return false;
+        };
+
+        for (const edge of edges) {
+            if (edge.source === edge.target) {
+                // quiver does not permit loops.
+                dot_incompatibilities.add("loops");
+                continue;
+            }
+            const [source, target] = [vertices.get(edge.source), vertices.get(edge.target)];
+
+            let label = "";
+            let label_colour = Colour.black();
+            const options = { curve: 0 };
+            const style = {
+                name: "arrow",
+                tail: { name: "none" },
+                body: { name: "cell" },
+                head: { name: directed ? "arrowhead" : "none" },
+            };
+            let [head, tail] = [null, null];
+            let dir = directed ? "forward" : "none";
+            for (const [key, value] of edge.attributes) {
+                const escapes = {
+                    T: edge.source,
+                    H: edge.target,
+                    E: `${edge.source}${directed ? "->" : "--"}${edge.target}`,
+                    G: graph_name,
+                };
+                switch (key) {
+                    case "label":
+                        label = convert_label(value, escapes);
+                        break;
+                    case "xlabel":
+                        if (!edge.attributes.has("label")) {
+                            label = convert_label(value, escapes);
+                        }
+                        break;
+                    case "fontcolor":
+                        label_colour = convert_colour(value.value) || label_colour;
+                        break;
+                    case "color":
+                        // Parallel lines are drawn using a colour list in which the colours are
+                        // separated by `invis`, as in `export`.
+                        const colours = value.value.split(":").map((colour) => colour.trim());
+                        const lines = colours.length > 1 && colours.length % 2 === 1
+                            && colours.every((colour, i) => (i % 2 === 1) === (colour === "invis"));
+                        if (lines) {
+                            options.level = (colours.length + 1) / 2;
+                        }
+                        const colour = convert_colour(lines ? colours[0] : value.value);
+                        if (colour !== null) {
+                            options.colour = colour;
+                        }
+                        break;
+                    case "style":
+                        for (const name of value.value.split(",").map((name) => name.trim())) {
+                            switch (name) {
+                                case "dashed":
+                                case "dotted":
+                                    style.body.name = name;
+                                    break;
+                                case "invis":
+                                    style.body.name = "none";
+                                    [head, tail] = [{ name: "none" }, { name: "none" }];
+                                    break;
+                                case "solid":
+                                    break;
+                                default:
+                                    dot_incompatibilities.add(`the \`${name}\` edge style`);
+                                    break;
+                            }
+                        }
+                        break;
+                    case "dir":
+                        dir = value.value;
+                        break;
+                    case "arrowhead":
+                        head = convert_arrow(value.value, "head");
+                        break;
+                    case "arrowtail":
+                        tail = convert_arrow(value.value, "tail");
+                        break;
+                    default:
+                        dot_incompatibilities.add(`the \`${key}\` edge attribute`);
+                        break;
+                }
+            }
+
+            // `dir` determines which of the arrowheads are drawn.
+            switch (dir) {
+                case "forward":
+                    style.head = head || { name: "arrowhead" };
+                    break;
+                case "back":
+                    style.head = { name: "none" };
+                    style.tail = tail || { name: "arrowhead" };
+                    break;
+                case "both":
+                    style.head = head || { name: "arrowhead" };
+                    style.tail = tail || { name: "arrowhead" };
+                    break;
+                case "none":
+                    style.head = { name: "none" };
+                    break;
+                default:
+                    dot_incompatibilities.add(`the \`dir\` value \`${dir}\``);
+                    break;
+            }
+
+            // Spread out parallel edges symmetrically. Edges in the opposite direction curve the
+            // other way, so that they end up on the same side.
+            const key = endpoints_key(edge);
+            const count = parallel.get(key);
+            const k = seen.get(key) || 0;
+            seen.set(key, k + 1);
+            const orientation = edge.source === [edge.source, edge.target].sort()[0] ? 1 : -1;
+            options.curve = (2 * k - (count - 1)) * orientation;
+            if (options.curve === 0 && passes_through_vertex(source.position, target.position)) {
+                options.curve = 2;
+            }
+
+            const cell = new Edge(
+                ui,
+                label,
+                source,
+                target,
+                Edge.default_options(options, style),
+                label_colour,
+            );
+            cells.push(cell);
+        }
+
+        QuiverImportExport.complete_import(ui, cells);
+
+        return {
+            data: cells,
+            metadata: { dot_incompatibilities },
+        };
+    }
+};
+
+QuiverExport.typst = new class extends QuiverExport {
+    /// Exports the diagram as Typst code using the fletcher package. Labels are converted from
+    /// LaTeX to Typst mathematics where possible. Features that cannot be represented in fletcher
+    /// are noted with a comment in the output, and reported in `metadata.typst_incompatibilities`.
+    export(quiver, settings, options, definitions) {
+        const typst_incompatibilities = new Set();
+
+        // Typst mathematical symbols corresponding to LaTeX commands.
+        const SYMBOLS = new Map([
+            ...[
+                "alpha", "beta", "gamma", "delta", "zeta", "eta", "theta", "iota", "kappa",
+                "lambda", "mu", "nu", "xi", "pi", "rho", "sigma", "tau", "upsilon", "chi", "psi",
+                "omega", "Gamma", "Delta", "Theta", "Lambda", "Xi", "Pi", "Sigma", "Upsilon",
+                "Phi", "Psi", "Omega", "ell", "forall", "exists", "nabla", "top", "bot", "in",
+                "times", "bullet", "prime", "star", "ast", "emptyset", "colon", "quad", "sin",
+                "cos", "tan", "log", "ln", "exp", "lim", "max", "min", "sup", "inf", "det", "dim",
+                "ker", "hom", "arg", "deg",
+            ].map((name) => [name, name]),
+            ["epsilon", "epsilon.alt"], ["varepsilon", "epsilon"], ["phi", "phi.alt"],
+            ["varphi", "phi"], ["vartheta", "theta.alt"], ["qquad", "wide"],
+            ["to", "arrow.r"], ["rightarrow", "arrow.r"], ["leftarrow", "arrow.l"],
+            ["Rightarrow", "arrow.r.double"], ["Leftarrow", "arrow.l.double"],
+            ["mapsto", "arrow.r.bar"], ["hookrightarrow", "arrow.r.hook"],
+            ["leftrightarrow", "arrow.l.r"], ["otimes", "times.circle"],
+            ["oplus", "plus.circle"], ["circ", "compose"], ["cdot", "dot.op"],
+            ["infty", "infinity"], ["dots", "dots.h"], ["ldots", "dots.h"], ["cdots", "dots.c"],
+            ["dashv", "tack.l"], ["vdash", "tack.r"], ["cong", "tilde.equiv"],
+            ["simeq", "tilde.eq"], ["sim", "tilde.op"], ["leq", "<="], ["le", "<="],
+            ["geq", ">="], ["ge", ">="], ["neq", "!="], ["ne", "!="], ["notin", "in.not"],
+            ["subseteq", "subset.eq"], ["subset", "subset"], ["cup", "union"], ["cap", "sect"],
+            ["varnothing", "emptyset"], ["partial", "diff"], ["int", "integral"], ["sum", "sum"],
+            ["prod", "product"], ["coprod", "product.co"], ["amalg", "product.co"],
+            ["wedge", "and"], ["vee", "or"], ["neg", "not"], ["mid", "divides"],
+            ["langle", "angle.l"], ["rangle", "angle.r"],
+        ]);
+        // Typst functions corresponding to LaTeX commands taking arguments.
+        const FUNCTIONS = new Map([
+            ["mathbb", "bb"], ["mathcal", "cal"], ["mathscr", "scr"], ["mathfrak", "frak"],
+            ["mathbf", "bold"], ["mathrm", "upright"], ["mathsf", "sans"], ["mathit", "italic"],
+            ["mathtt", "mono"], ["bar", "overline"], ["overline", "overline"],
+            ["underline", "underline"], ["hat", "hat"], ["widehat", "hat"], ["tilde", "tilde"],
+            ["widetilde", "tilde"], ["vec", "arrow"], ["dot", "dot"], ["frac", "frac"],
+        ]);
+        // LaTeX commands that we may safely ignore.
+        const IGNORED = new Set(["left", "right", "big", "Big", "displaystyle"]);
+        // Characters that have the same meaning in LaTeX and Typst.
+        const PUNCTUATION = /[+\-=<>()[\]|,.;:!*?]/;
+
+        // Quotes a string for Typst.
+        const quote = (string) => `"${string.replace(/["\\]/g, (c) => `\\${c}`)}"`;
+
+        // Converts a LaTeX label into Typst mathematics. Returns `null` if the label contains
+        // LaTeX that we do not know how to convert.
+        const convert_math = (label) => {
+            let i = 0;
+            // The number of function arguments we are inside. Commas and semicolons separate
+            // function arguments in Typst, so must be quoted inside them.
+            let depth = 0;
+            // Returns the contents of a `{...}` group without converting it, e.g. for `\text`.
+            const verbatim_group = () => {
+                while (/\s/.test(label[i])) {
+                    ++i;
+                }
+                if (label[i] !== "{") {
+                    return null;
+                }
+                const start = ++i;
+                for (let depth = 1; i < label.length; ++i) {
+                    depth += label[i] === "{" ? 1 : label[i] === "}" ? -1 : 0;
+                    if (depth === 0) {
+                        return label.slice(start, i++);
+                    }
+                }
+                return null;
+            };
+            // Converts a single atom: a symbol, command, or group. Returns `""` for whitespace.
+            const convert_atom = () => {
+                const c = label[i++];
+                if (typeof c === "undefined") {
+                    return null;
+                }
+                if (/\s/.test(c)) {
+                    return "";
+                }
+                if (depth > 0 && (c === "," || c === ";")) {
+                    return quote(c);
+                }
+                if (/[a-zA-Z]/.test(c) || PUNCTUATION.test(c)) {
+                    return c;
+                }
+                if (/[0-9]/.test(c)) {
+                    const number = label.slice(i - 1).match(/^[0-9]+(?:\.[0-9]+)?/)[0];
+                    i += number.length - 1;
+                    return number;
+                }
+                switch (c) {
+                    case "/":
+                        return "slash";
+                    case "{":
+                        const group = convert_group();
+                        if (group === null || label[i++] !== "}") {
+                            return null;
+                        }
+                        return group;
+                    case "\\":
+                        const name = label.slice(i).match(/^(?:[a-zA-Z]+|[^])/);
+                        if (name === null) {
+                            return null;
+                        }
+                        i += name[0].length;
+                        switch (name[0]) {
+                            case "{":
+                            case "}":
+                                return `\\${name[0]}`;
+                            case ",":
+                                return "thin";
+                            case ":":
+                            case ">":
+                                return "med";
+                            case ";":
+                                return "thick";
+                            case " ":
+                                return "space";
+                            case "!":
+                                return "";
+                            case "text":
+                            case "operatorname":
+                                const text = verbatim_group();
+                                if (text === null || text.includes("\\")) {
+                                    return null;
+                                }
+                                return name[0] === "text" ? quote(text) : `op(${quote(text)})`;
+                        }
+                        if (SYMBOLS.has(name[0])) {
+                            return SYMBOLS.get(name[0]);
+                        }
+                        if (IGNORED.has(name[0])) {
+                            return "";
+                        }
+                        if (FUNCTIONS.has(name[0])) {
+                            const args = [];
+                            ++depth;
+                            for (let n = name[0] === "frac" ? 2 : 1; n > 0; --n) {
+                                let arg;
+                                while ((arg = convert_atom()) === "");
+                                if (arg === null) {
+                                    return null;
+                                }
+                                args.push(arg);
+                            }
+                            --depth;
+                            return `${FUNCTIONS.get(name[0])}(${args.join(", ")})`;
+                        }
+                        return null;
+                }
+                return null;
+            };
+            // Converts a sequence of atoms, up to the end of the label, or to a closing `}`.
+            const convert_group = () => {
+                const atoms = [];
+                while (i < label.length && label[i] !== "}") {
+                    const c = label[i];
+                    if (c === "_" || c === "^" || c === "'") {
+                        ++i;
+                        if (atoms.length === 0) {
+                            atoms.push("\"\"");
+                        }
+                        if (c === "'") {
+                            atoms[atoms.length - 1] += "'";
+                            continue;
+                        }
+                        let script;
+                        while ((script = convert_atom()) === "");
+                        if (script === null) {
+                            return null;
+                        }
+                        // Only simple subscripts and superscripts may be written without
+                        // parentheses.
+                        if (!/^[a-zA-Z0-9.]+$/.test(script)) {
+                            script = `(${script})`;
+                        }
+                        atoms[atoms.length - 1] += `${c}${script}`;
+                        continue;
+                    }
+                    const atom = convert_atom();
+                    if (atom === null) {
+                        return null;
+                    }
+                    if (atom !== "") {
+                        atoms.push(atom);
+                    }
+                }
+                // Consecutive letters must be separated in Typst, or else they will be treated as
+                // a single variable.
+                return atoms.join(" ");
+            };
+            const math = convert_group();
+            return i === label.length ? math : null;
+        };
+
+        const lines = [];
+
+        // Converts a label into Typst content, returning `null` for empty labels.
+        const convert_label = (label, colour) => {
+            if (label.trim() === "") {
+                return null;
+            }
+            let math = convert_math(label);
+            if (math === null) {
+                typst_incompatibilities.add("labels that could not be converted from LaTeX");
+                lines.push(`// The label \`${label}\` could not be converted from LaTeX.`);
+                math = label;
+            }
+            const content = `$${math}$`;
+            return colour.is_not_black() ?
+                `text(fill: rgb(${quote(colour.hex())}), ${content})` : content;
+        };
+
+        // Describes an edge for a comment, by its endpoints, label, and style if it is not drawn as
+        // an arrow, e.g. `A -> D (corner)`. Vertices without labels are described by their
+        // coordinates.
+        const describe = (cell) => {
+            if (cell.is_vertex()) {
+                return cell.label.trim() !== "" ? cell.label.replace(/\s+/g, " ").trim()
+                    : coordinates(cell);
+            }
+            const endpoints = [cell.source, cell.target].map((endpoint) => {
+                return endpoint.is_vertex() ? describe(endpoint) : `(${describe(endpoint)})`;
+            });
+            const label = cell.label.trim() !== "" ?
+                ` "${cell.label.replace(/\s+/g, " ").trim()}"` : "";
+            const style = cell.options.style.name !== "arrow" ?
+                ` (${cell.options.style.name})` : "";
+            return `${endpoints.join(" -> ")}${label}${style}`;
+        };
+
+        // Notes a feature of `edge` that cannot be represented in fletcher, with a comment in the
+        // output. `omitted` describes whether the edge is omitted entirely, or approximated (in
+        // which case the comment precedes the edge).
+        const unsupported = (edge, feature, omitted) => {
+            typst_incompatibilities.add(feature);
+            lines.push(`// ${omitted ? "Omitted" : "Approximated"} ${
+                describe(edge)
+            }: fletcher does not support ${feature}.`);
+        };
+
+        // Output the vertices, with positions relative to the top-left vertex. fletcher uses a
+        // y-axis that points downwards, like quiver.
+        let offset = new Position(Infinity, Infinity);
+        for (const vertex of quiver.cells[0]) {
+            offset = offset.min(vertex.position);
+        }
+        const coordinates = (vertex) => {
+            const position = vertex.position.sub(offset);
+            return `(${position.x}, ${position.y})`;
+        };
+        for (const vertex of quiver.cells[0]) {
+            const label = convert_label(vertex.label, vertex.label_colour);
+            if (label !== null) {
+                lines.push(`node(${coordinates(vertex)}, ${label}),`);
+            }
+        }
+
+        // Output the edges. fletcher only permits edges between nodes, so we cannot export edges
+        // between edges.
+        for (let level = 1; level < quiver.cells.length; ++level) {
+            for (const edge of quiver.cells[level]) {
+                if (!edge.source.is_vertex() || !edge.target.is_vertex()) {
+                    unsupported(edge, "arrows between arrows", true);
+                    continue;
+                }
+                if (edge.options.style.name !== "arrow") {
+                    unsupported(edge, QuiverExport.unsupported_style(edge), true);
+                    continue;
+                }
+
+                const args = [coordinates(edge.source), coordinates(edge.target)];
+                const label = convert_label(edge.label, edge.label_colour);
+                if (label !== null) {
+                    args.push(label);
+                }
+
+                // fletcher describes the style of an edge by a string of marks, such as `|->`.
+                const { tail, body, head } = edge.options.style;
+                let marks = "";
+                switch (tail.name) {
+                    case "maps to":
+                        marks += "|";
+                        break;
+                    case "mono":
+                        marks += ">";
+                        break;
+                    case "hook":
+                        marks += tail.side === "top" ? "hook" : "hook'";
+                        break;
+                    case "arrowhead":
+                        marks += "<";
+                        break;
+                }
+                switch (body.name) {
+                    case "dashed":
+                        marks += "--";
+                        break;
+                    case "dotted":
+                        marks += "..";
+                        break;
+                    case "squiggly":
+                        marks += "~";
+                        break;
+                    case "barred":
+                        unsupported(edge, "barred arrows", false);
+                        marks += "-";
+                        break;
+                    default:
+                        marks += "-";
+                        break;
+                }
+                switch (head.name) {
+                    case "arrowhead":
+                        marks += ">";
+                        break;
+                    case "epi":
+                        marks += ">>";
+                        break;
+                    case "harpoon":
+                        marks += head.side === "top" ? "harpoon" : "harpoon'";
+                        break;
+                }
+                args.push(quote(marks));
+
+                const parameters = {};
+                if (body.name === "none") {
+                    if (head.name !== "none" || tail.name !== "none") {
+                        unsupported(edge, "arrows without bodies", false);
+                    }
+                    parameters.stroke = "none";
+                } else if (edge.options.colour.is_not_black()) {
+                    parameters.stroke = `rgb(${quote(edge.options.colour.hex())})`;
+                }
+                // Multiple lines are drawn by extruding the edge.
+                if (edge.options.level > 1) {
+                    parameters.extrude = `(${Array.from(
+                        new Array(edge.options.level),
+                        (_, k) => 4 * k - 2 * (edge.options.level - 1),
+                    ).join(", ")})`;
+                }
+
+                switch (edge.options.label_alignment) {
+                    case "centre":
+                        parameters["label-side"] = "center";
+                        break;
+                    case "over":
+                        parameters["label-side"] = "center";
+                        parameters["label-fill"] = "false";
+                        break;
+                    case "right":
+                        parameters["label-side"] = "right";
+                        break;
+                }
+                if (edge.options.label_position !== 50) {
+                    parameters["label-pos"] = edge.options.label_position / 100;
+                }
+
+                // fletcher bends edges by an angle, rather than to a fixed height, so we compute
+                // the angle at which a quiver edge leaves its source. Positive angles bend to the
+                // left in fletcher, whereas positive curves bend to the right in quiver. We assume
+                // each grid cell has the default size of 128px.
+                if (edge.options.curve !== 0) {
+                    const length = edge.target.position.sub(edge.source.position).length() * 128;
+                    const angle = Math.atan2(
+                        edge.options.curve * CONSTANTS.CURVE_HEIGHT * 4,
+                        length,
+                    ) * 180 / Math.PI;
+                    parameters.bend = `${-Math.round(angle)}deg`;
+                }
+                if (edge.options.offset !== 0) {
+                    parameters.shift = `${-edge.options.offset * 3}pt`;
+                }
+                if (edge.options.shorten.source !== 0 || edge.options.shorten.target !== 0) {
+                    unsupported(edge, "shortened arrows", false);
+                }
+
+                for (const [key, value] of Object.entries(parameters)) {
+                    args.push(`${key}: ${value}`);
+                }
+                lines.push(`edge(${args.join(", ")}),`);
+            }
+        }
+
+        return {
+            data: `// ${
+                QuiverImportExport.base64.export(quiver, settings, options, definitions).data
+            }\n#import "@preview/fletcher:0.5.8" as fletcher: diagram, node, edge\n${
+                lines.length > 0 ? `#diagram(\n${
+                    lines.map((line) => `\t${line}`).join("\n")
+                }\n)` : "#diagram()"
+            }`,
+            metadata: { typst_incompatibilities },
+        };
+    }
+};
+
+QuiverExport.xypic = new class extends QuiverExport {
+    /// Exports the diagram as an xy-pic `\xymatrix`. Each arrow is given by an `\ar` command in the
+    /// entry of its source. Features that cannot be represented in xy-pic are listed in a comment
+    /// at the start of the output, and reported in `metadata.xypic_incompatibilities`.
+    export(quiver, settings, options, definitions) {
+        const xypic_incompatibilities = new Set();
+
+        // The `\ar` commands for the arrows leaving each vertex. xy-pic only permits arrows
+        // between entries, so we cannot export edges between edges.
+        const arrows = new Map(Array.from(quiver.cells[0]).map((vertex) => [vertex, []]));
+        for (let level = 1; level < quiver.cells.length; ++level) {
+            for (const edge of quiver.cells[level]) {
+                if (!edge.source.is_vertex() || !edge.target.is_vertex()) {
+                    xypic_incompatibilities.add("arrows between arrows");
+                    continue;
+                }
+                if (edge.options.style.name !== "arrow") {
+                    xypic_incompatibilities.add(QuiverExport.unsupported_style(edge));
+                    continue;
+                }
+
+                let ar = "\\ar";
+
+                // The style of the arrow, which is given by a tail, a body, and a head.
+                const { tail, body, head } = edge.options.style;
+                let style = "";
+                switch (tail.name) {
+                    case "maps to":
+                        style += "|";
+                        break;
+                    case "mono":
+                        style += ">";
+                        break;
+                    case "hook":
+                        style += tail.side === "top" ? "^{(}" : "_{(}";
+                        break;
+                    case "arrowhead":
+                        style += "<";
+                        break;
+                }
+                switch (body.name) {
+                    case "dashed":
+                        style += "--";
+                        break;
+                    case "dotted":
+                        style += ".";
+                        break;
+                    case "squiggly":
+                        style += "~";
+                        break;
+                    case "none":
+                        if (head.name !== "none" || tail.name !== "none") {
+                            xypic_incompatibilities.add("arrows without bodies");
+                        }
+                        break;
+                    default:
+                        style += "-";
+                        break;
+                }
+                if (body.name !== "none") {
+                    switch (head.name) {
+                        case "arrowhead":
+                            style += ">";
+                            break;
+                        case "epi":
+                            style += ">>";
+                            break;
+                        case "harpoon":
+                            style += head.side === "top" ? "^>" : "_>";
+                            break;
+                    }
+                } else {
+                    // We draw no arrow at all.
+                    style = "";
+                }
+                // Double and triple arrows are drawn by specifying the number of lines.
+                if (edge.options.level > 1) {
+                    if (edge.options.level > 3) {
+                        xypic_incompatibilities.add("quadruple arrows or higher");
+                    }
+                    if (tail.name !== "none" || head.name === "epi" || head.name === "harpoon") {
+                        xypic_incompatibilities.add(
+                            "double arrows or higher with tails or special heads"
+                        );
+                    }
+                }
+                if (style !== "->" || edge.options.level > 1) {
+                    ar += `@${edge.options.level > 1 ? edge.options.level : ""}{${style}}`;
+                }
+
+                // Curves and offsets. In xy-pic, positive values bend or shift to the left,
+                // whereas in quiver, positive values bend or shift to the right.
+                if (edge.options.curve !== 0) {
+                    ar += `@/${edge.options.curve > 0 ? "_" : "^"}${
+                        Math.abs(edge.options.curve) * CONSTANTS.CURVE_HEIGHT / 4
+                    }pt/`;
+                }
+                if (edge.options.offset !== 0) {
+                    ar += `@<${-edge.options.offset * 2.5}pt>`;
+                }
+
+                // The direction to the target, e.g. `[rrd]`.
+                const displacement = edge.target.position.sub(edge.source.position);
+                ar += `[${
+                    (displacement.x >= 0 ? "r" : "l").repeat(Math.abs(displacement.x))
+                }${
+                    (displacement.y >= 0 ? "d" : "u").repeat(Math.abs(displacement.y))
+                }]`;
+
+                // The label, which may be placed above (i.e. to the left of) the arrow, below it,
+                // or inline.
+                if (edge.label !== "") {
+                    ar += {
+                        left: "^",
+                        right: "_",
+                        centre: "|",
+                        over: "|",
+                    }[edge.options.label_alignment];
+                    if (edge.options.label_position !== 50) {
+                        ar += `(${edge.options.label_position / 100})`;
+                    }
+                    ar += `{${edge.label}}`;
+                }
+                if (body.name === "barred") {
+                    // We draw the bar as a tip in the middle of the arrow.
+                    ar += "|@{|}";
+                }
+
+                if (edge.options.colour.is_not_black() || edge.label_colour.is_not_black()) {
+                    xypic_incompatibilities.add("colours");
+                }
+                if (edge.options.shorten.source !== 0 || edge.options.shorten.target !== 0) {
+                    xypic_incompatibilities.add("shortened arrows");
+                }
+
+                arrows.get(edge.source).push(ar);
+            }
+        }
+
+        // Output the grid, row by row.
+        const { output } = QuiverExport.grid(quiver, (vertex) => {
+            if (vertex.label_colour.is_not_black()) {
+                xypic_incompatibilities.add("colours");
+            }
+            return [`{${vertex.label}}`, ...arrows.get(vertex)].join(" ");
+        });
+
+        const link = QuiverImportExport.base64.export(quiver, settings, options, definitions).data;
+        const unsupported = xypic_incompatibilities.size > 0 ?
+            `% The following could not be represented in xy-pic: ${
+                Array.from(xypic_incompatibilities).sort().join(", ")
+            }.\n` : "";
+        return {
+            data: `% ${link}\n${unsupported}\\xymatrix{\n${
+                output.length > 0 ? `${
+                    output.split("\n").map(line => `\t${line}`).join("\n")
+                }\n` : ""
+            }}`,
+            metadata: { xypic_incompatibilities },
+        };
+    }
+};
+
+QuiverExport.alt_text = new class extends QuiverExport {
+    /// Exports a textual description of the diagram, suitable for use as alternative text. The
+    /// objects are listed row by row, followed by the arrows in reading order. Labels are given as
+    /// LaTeX, followed by a spoken form where the LaTeX is simple enough to transliterate.
+    export(quiver) {
+        // Spoken forms of LaTeX commands that take no arguments.
+        const WORDS = new Map([
+            ...[
+                "alpha", "beta", "gamma", "delta", "epsilon", "zeta", "eta", "theta", "iota",
+                "kappa", "lambda", "mu", "nu", "xi", "pi", "rho", "sigma", "tau", "upsilon", "phi",
+                "chi", "psi", "omega", "Gamma", "Delta", "Theta", "Lambda", "Xi", "Pi", "Sigma",
+                "Upsilon", "Phi", "Psi", "Omega", "ell", "top", "bot", "in", "times", "star",
+                "sin", "cos", "tan", "log", "ln", "exp", "lim", "colim", "max", "min", "sup",
+                "inf", "det", "dim", "ker", "hom", "Hom", "id",
+            ].map((name) => [name, name]),
+            ["varepsilon", "epsilon"], ["varphi", "phi"], ["vartheta", "theta"],
+            ["to", "to"], ["rightarrow", "to"], ["leftarrow", "from"], ["mapsto", "maps to"],
+            ["Rightarrow", "implies"], ["Leftarrow", "is implied by"],
+            ["hookrightarrow", "includes into"], ["otimes", "tensor"],
+            ["oplus", "direct sum"], ["circ", "compose"], ["cdot", "dot"], ["bullet", "bullet"],
+            ["ast", "star"], ["infty", "infinity"], ["dots", "dots"], ["ldots", "dots"],
+            ["cdots", "dots"], ["dashv", "left adjoint to"], ["vdash", "right adjoint to"],
+            ["cong", "isomorphic to"], ["simeq", "equivalent to"], ["equiv", "equivalent to"],
+            ["leq", "less than or equal to"], ["geq", "greater than or equal to"],
+            ["neq", "not equal to"], ["subseteq", "subset of"], ["subset", "subset of"],
+            ["cup", "union"], ["cap", "intersection"], ["emptyset", "empty set"],
+            ["varnothing", "empty set"], ["partial", "partial"], ["nabla", "nabla"],
+            ["int", "integral"], ["sum", "sum"], ["prod", "product"], ["coprod", "coproduct"],
+            ["amalg", "coproduct"], ["forall", "for all"], ["exists", "there exists"],
+            ["colon", ":"], ["prime", "prime"],
+        ]);
+        // Spoken forms of LaTeX commands that take a single argument. An empty prefix means that
+        // the argument is simply read aloud.
+        const PREFIXES = new Map([
+            ["mathcal", "script"], ["mathscr", "script"], ["mathbb", "blackboard"],
+            ["mathfrak", "fraktur"], ["mathbf", "bold"], ["bar", "bar"], ["overline", "bar"],
+            ["hat", "hat"], ["widehat", "hat"], ["tilde", "tilde"], ["widetilde", "tilde"],
+            ["mathrm", ""], ["mathsf", ""], ["mathit", ""], ["mathtt", ""], ["text", ""],
+            ["textrm", ""], ["operatorname", ""], ["underline", ""],
+        ]);
+        // LaTeX commands which are spoken as a space, or which may be ignored.
+        const SPACES = new Set([",", ";", ":", "!", " ", "quad", "qquad"]);
+        const IGNORED = new Set(["left", "right", "big", "Big", "displaystyle"]);
+        // Spoken forms of superscripts with a conventional reading.
+        const SUPERSCRIPTS = new Map([
+            ["-1", "inverse"], ["op", "op"], ["*", "star"], ["\\ast", "star"], ["\\prime", "prime"],
+        ]);
+
+        // Converts a LaTeX label into a spoken form. Returns `null` if the label contains LaTeX
+        // that we do not know how to read.
+        const speak = (label) => {
+            let i = 0;
+            // Returns the source of the next atom (a character, command, or group), without
+            // converting it.
+            const source = () => {
+                while (/\s/.test(label[i])) {
+                    ++i;
+                }
+                const start = i;
+                if (label[i] === "{") {
+                    for (let depth = 0; i < label.length; ++i) {
+                        depth += label[i] === "{" ? 1 : label[i] === "}" ? -1 : 0;
+                        if (depth === 0) {
+                            return label.slice(start + 1, i++);
+                        }
+                    }
+                    return null;
+                }
+                if (label[i] === "\\") {
+                    const command = label.slice(i).match(/^\\(?:[a-zA-Z]+|.)/);
+                    if (command === null) {
+                        return null;
+                    }
+                    i += command[0].length;
+                    return command[0];
+                }
+                return i < label.length ? label[i++] : null;
+            };
+            // Converts the next atom into a spoken form.
+            const atom = () => {
+                const c = label[i];
+                if (/\s/.test(c)) {
+                    ++i;
+                    return " ";
+                }
+                if (c === "_" || c === "^") {
+                    ++i;
+                    const script = source();
+                    if (script === null) {
+                        return null;
+                    }
+                    if (c === "^" && SUPERSCRIPTS.has(script.trim())) {
+                        return ` ${SUPERSCRIPTS.get(script.trim())} `;
+                    }
+                    const spoken = speak(script);
+                    return spoken !== null ?
+                        ` ${c === "_" ? "sub" : "superscript"} ${spoken} ` : null;
+                }
+                if (c === "'") {
+                    ++i;
+                    return " prime ";
+                }
+                const next = source();
+                if (next === null) {
+                    return null;
+                }
+                if (c === "{") {
+                    return speak(next);
+                }
+                if (c !== "\\") {
+                    return next;
+                }
+                const command = next.slice(1);
+                if (SPACES.has(command)) {
+                    return " ";
+                }
+                if (IGNORED.has(command)) {
+                    return "";
+                }
+                if (WORDS.has(command)) {
+                    return ` ${WORDS.get(command)} `;
+                }
+                if (/^[{}]$/.test(command)) {
+                    return command;
+                }
+                if (PREFIXES.has(command)) {
+                    const argument = source();
+                    const spoken = argument !== null ? speak(argument) : null;
+                    return spoken !== null ? ` ${PREFIXES.get(command)} ${spoken} ` : null;
+                }
+                return null;
+            };
+
+            let output = "";
+            while (i < label.length) {
+                const spoken = atom();
+                if (spoken === null) {
+                    return null;
+                }
+                output += spoken;
+            }
+            return output.replace(/\s+/g, " ").trim();
+        };
+
+        // Formats a label as LaTeX, followed by its spoken form if this differs.
+        const format_label = (label) => {
+            const spoken = speak(label);
+            return spoken !== null && spoken !== label.trim() ? `${label} (spoken: ${spoken})`
+                : label;
+        };
+
+        // The vertices, in reading order.
+        const vertices = Array.from(quiver.cells[0]).sort((a, b) => {
+            return a.position.y - b.position.y || a.position.x - b.position.x;
+        });
+        let offset = new Position(Infinity, Infinity);
+        for (const vertex of vertices) {
+            offset = offset.min(vertex.position);
+        }
+        // The edges, in reading order: first by level, and then by the positions of their source
+        // and target.
+        const edges = [];
+        for (let level = 1; level < quiver.cells.length; ++level) {
+            edges.push(...quiver.cells[level]);
+        }
+        const order = new Map(vertices.map((vertex, index) => [vertex, index]));
+        const compare = (a, b) => {
+            return order.get(a.source) - order.get(b.source)
+                || order.get(a.target) - order.get(b.target);
+        };
+        for (let start = 0; start < edges.length;) {
+            let end = start;
+            while (end < edges.length && edges[end].level === edges[start].level) {
+                ++end;
+            }
+            const level = edges.slice(start, end).sort(compare);
+            level.forEach((edge, index) => order.set(edge, vertices.length + start + index));
+            edges.splice(start, level.length, ...level);
+            start = end;
+        }
+
+        // Each cell is referred to by its label, if this is unambiguous, and otherwise by its
+        // position (for objects), or by its source and target (for arrows). The spoken form of
+        // each label is given only where the cell is declared, to keep the description concise.
+        const counts = new Map();
+        for (const cell of [...vertices, ...edges]) {
+            counts.set(cell.label, (counts.get(cell.label) || 0) + 1);
+        }
+        const names = new Map();
+        for (const vertex of vertices) {
+            const position = vertex.position.sub(offset);
+            names.set(vertex, vertex.label.trim() !== "" && counts.get(vertex.label) === 1 ?
+                vertex.label.trim()
+                : `the object at row ${position.y + 1}, column ${position.x + 1}`);
+        }
+        for (const edge of edges) {
+            names.set(edge, edge.label.trim() !== "" && counts.get(edge.label) === 1 ?
+                edge.label.trim()
+                : `the arrow from ${names.get(edge.source)} to ${names.get(edge.target)}`);
+        }
+
+        // Describes the style of an arrow, e.g. "dashed, monomorphism".
+        const describe_style = (edge) => {
+            const { tail, body, head } = edge.options.style;
+            if (tail.name === "none" && body.name === "none" && head.name === "none") {
+                return ["invisible"];
+            }
+            const descriptions = [];
+            if (edge.options.level > 1) {
+                descriptions.push({ 2: "double", 3: "triple" }[edge.options.level]
+                    || `${edge.options.level}-fold`);
+            }
+            switch (body.name) {
+                case "dashed":
+                case "dotted":
+                case "squiggly":
+                case "barred":
+                    descriptions.push(body.name);
+                    break;
+                case "none":
+                    descriptions.push("with no body");
+                    break;
+            }
+            switch (tail.name) {
+                case "maps to":
+                    descriptions.push("maps to");
+                    break;
+                case "mono":
+                    descriptions.push("monomorphism");
+                    break;
+                case "hook":
+                    descriptions.push("hooked");
+                    break;
+                case "arrowhead":
+                    descriptions.push("with an arrowhead at the tail");
+                    break;
+            }
+            switch (head.name) {
+                case "epi":
+                    descriptions.push("epimorphism");
+                    break;
+                case "harpoon":
+                    descriptions.push("harpoon");
+                    break;
+                case "none":
+                    descriptions.push("with no head");
+                    break;
+            }
+            if (edge.options.curve !== 0) {
+                descriptions.push("curved");
+            }
+            return descriptions;
+        };
+
+        const plural = (n, noun) => `${n} ${noun}${n !== 1 ? "s" : ""}`;
+        const lines = [`A diagram with ${plural(vertices.length, "object")} and ${
+            plural(edges.length, "arrow")
+        }.`];
+
+        if (vertices.length > 0) {
+            lines.push("", "Objects, row by row:");
+            for (let start = 0; start < vertices.length;) {
+                let end = start;
+                while (end < vertices.length
+                    && vertices[end].position.y === vertices[start].position.y
+                ) {
+                    ++end;
+                }
+                lines.push(`- Row ${vertices[start].position.y - offset.y + 1}: ${
+                    vertices.slice(start, end).map((vertex) => {
+                        return vertex.label.trim() !== "" ? format_label(vertex.label)
+                            : "an unlabelled object";
+                    }).join(", ")
+                }.`);
+                start = end;
+            }
+        }
+
+        if (edges.length > 0) {
+            lines.push("", "Arrows:");
+            for (const edge of edges) {
+                const [source, target] = [names.get(edge.source), names.get(edge.target)];
+                const label = edge.label.trim() !== "" ? `${format_label(edge.label)} : ` : "";
+                switch (edge.options.style.name) {
+                    case "adjunction":
+                        lines.push(`- An adjunction: ${source} is left adjoint to ${target}.`);
+                        break;
+                    case "corner":
+                    case "corner-inverse":
+                        lines.push(`- ${
+                            edge.options.style.name === "corner-inverse" ? "An inverted" : "A"
+                        } pullback or pushout corner at ${source}, towards ${target}.`);
+                        break;
+                    default:
+                        lines.push(`- ${label}${source} → ${target}${
+                            describe_style(edge).map((description) => `, ${description}`).join("")
+                        }.`);
+                        break;
+                }
+            }
+        }
+
+        return {
+            data: lines.join("\n"),
+            metadata: {},
+        };
+    }
+};
+
+QuiverImportExport.text = new class extends QuiverImportExport {
+    // The text format is a human-readable representation of a diagram, intended to be embedded in
+    // source files and compared with `diff`. For example:
+    //
+    //     quiver {
+    //         A & B
+    //         C & D : "D \times E"
+    //         f = A -> B : "f"
+    //         A -> C : "g" [swap, hook]
+    //         B -> D : "h" [dashed, two heads]
+    //         C -> D : "k" [{"colour": [240, 60, 60]}]
+    //     }
+    //
+    // Each line inside `quiver { ... }` is a statement, and `#` begins a comment.
+    // - A row statement declares the objects in the next row of the grid, separated by `&`. An
+    //   entry may be empty, to leave a gap. Each object has a name, which is also used as its label
+    //   unless a label is given after a `:`. Positions may be given explicitly by the `x` and `y`
+    //   attributes, e.g. `A [x=2, y=1]`.
+    // - An arrow statement declares an arrow between two objects or arrows, which may optionally
+    //   be given a name (so that other arrows may refer to it) with `name = source -> target`.
+    //   Arrows may only refer to arrows that have been declared before them.
+    // Labels are quoted by `"`, and a literal `"` is written `""`. Backslashes have no special
+    // meaning, so LaTeX may be written as is.
+    //
+    // Arrows may have the following attributes, which are named as in tikz-cd:
+    // - `swap`, `description` and `marking` for the label alignment;
+    // - `pos=0.3` for the label position;
+    // - `offset=n`, `curve=n`, `level=n` (for the number of lines), `shorten source=n` and
+    //   `shorten target=n` (as percentages);
+    // - `dashed`, `dotted`, `squiggly`, `barred` and `no body` for the body;
+    // - `maps to`, `tail`, `hook`, `hook'` and `tail reversed` for the tail;
+    // - `no head`, `two heads`, `harpoon` and `harpoon'` for the head;
+    // - `adjunction`, `corner` and `corner inverse` for arrows that are not drawn as arrows.
+    // Any other options, such as colours, may be given as a JSON object, using the same options as
+    // the base64 format (with the addition of `label_colour`). This means any diagram can be
+    // represented in the text format. JSON options are merged with the named attributes, which
+    // take precedence where both are given.
+
+    export(quiver) {
+        const IDENTIFIER = /^[a-zA-Z_][a-zA-Z0-9_']*$/;
+        const quote = (label) => `"${label.replace(/"/g, "\"\"")}"`;
+
+        // Give each object, and each arrow that is referred to by another arrow, a unique name. We
+        // use the label as the name where possible, as this is most readable.
+        const names = new Map();
+        const used = new Set();
+        const name_cell = (cell, prefix) => {
+            let name = cell.label;
+            if (!IDENTIFIER.test(name) || used.has(name)) {
+                let k = 0;
+                while (used.has(`${prefix}${k}`)) {
+                    ++k;
+                }
+                name = `${prefix}${k}`;
+            }
+            names.set(cell, name);
+            used.add(name);
+            return name;
+        };
+
+        // Formats the attribute list for a cell, including any options that do not have named
+        // attributes, which are given as a JSON object.
+        const format_attributes = (attributes, options) => {
+            if (Object.keys(options).length > 0) {
+                attributes.push(JSON.stringify(options));
+            }
+            return attributes.length > 0 ? ` [${attributes.join(", ")}]` : "";
+        };
+
+        // The name and label of a cell, as it appears in a statement.
+        const label = (cell, name) => {
+            return cell.label !== name ? ` : ${quote(cell.label)}` : "";
+        };
+        const label_colour = (cell) => {
+            return cell.label !== "" && cell.label_colour.is_not_black() ?
+                { label_colour: cell.label_colour } : {};
+        };
+
+        // Output the objects, row by row.
+        let offset = new Position(Infinity, Infinity);
+        let extent = new Position(-Infinity, -Infinity);
+        const entries = new Map();
+        for (const vertex of quiver.cells[0]) {
+            offset = offset.min(vertex.position);
+            extent = extent.max(vertex.position);
+            const name = name_cell(vertex, "v");
+            entries.set(`${vertex.position}`, `${name}${label(vertex, name)}${
+                format_attributes([], label_colour(vertex))
+            }`);
+        }
+        const rows = [];
+        for (let y = offset.y; y <= extent.y; ++y) {
+            const row = [];
+            for (let x = offset.x; x <= extent.x; ++x) {
+                row.push(entries.get(`${new Position(x, y)}`) || "");
+            }
+            rows.push(row);
+        }
+        // Pad each column to the same width, so that the grid is easy to read.
+        const widths = rows.length > 0 ? rows[0].map((_, x) => {
+            return Math.max(...rows.map((row) => row[x].length));
+        }) : [];
+        const lines = rows.map((row) => {
+            // We omit empty entries at the end of the row, but an empty row is written as `&`.
+            while (row.length > 1 && row[row.length - 1] === "") {
+                row.pop();
+            }
+            if (row.length === 1 && row[0] === "") {
+                return "&";
+            }
+            return row.map((entry, x) => {
+                return x + 1 < row.length ? entry.padEnd(widths[x]) : entry;
+            }).join(" & ").trimEnd();
+        });
+
+        // Output the arrows.
+        for (let level = 1; level < quiver.cells.length; ++level) {
+            for (const edge of quiver.cells[level]) {
+                const { label_alignment, ...options } = edge.options;
+                const delta = Object.assign(
+                    QuiverExport.delta(options, Edge.default_options({ level })),
+                    label_colour(edge),
+                );
+                const attributes = [];
+
+                const alignment = {
+                    left: null,
+                    right: "swap",
+                    centre: "description",
+                    over: "marking",
+                }[label_alignment];
+                if (alignment !== null && edge.label !== "") {
+                    attributes.push(alignment);
+                }
+                for (const key of ["label_position", "offset", "curve", "level"]) {
+                    if (delta.hasOwnProperty(key)) {
+                        attributes.push(key === "label_position" ?
+                            `pos=${delta[key] / 100}` : `${key}=${delta[key]}`);
+                        delete delta[key];
+                    }
+                }
+                if (delta.hasOwnProperty("shorten")) {
+                    for (const end of ["source", "target"]) {
+                        if (delta.shorten.hasOwnProperty(end)) {
+                            attributes.push(`shorten ${end}=${delta.shorten[end]}`);
+                        }
+                    }
+                    delete delta.shorten;
+                }
+
+                if (delta.hasOwnProperty("style")) {
+                    const style = delta.style;
+                    // Returns the attribute for a component of the style, if it has one, in which
+                    // case the component is removed from the delta.
+                    const take = (component, attributes) => {
+                        if (style.hasOwnProperty(component)) {
+                            const { name, side = "" } = style[component];
+                            const attribute = attributes[`${name}${side}`];
+                            if (typeof attribute !== "undefined") {
+                                delete style[component];
+                                return [attribute];
+                            }
+                        }
+                        return [];
+                    };
+                    if (style.hasOwnProperty("name")) {
+                        attributes.push(style.name === "corner-inverse" ?
+                            "corner inverse" : style.name);
+                        delete style.name;
+                    }
+                    attributes.push(...take("tail", {
+                        "maps to": "maps to",
+                        mono: "tail",
+                        hooktop: "hook",
+                        hookbottom: "hook'",
+                        arrowhead: "tail reversed",
+                    }));
+                    attributes.push(...take("body", {
+                        dashed: "dashed",
+                        dotted: "dotted",
+                        squiggly: "squiggly",
+                        barred: "barred",
+                        none: "no body",
+                    }));
+                    attributes.push(...take("head", {
+                        none: "no head",
+                        epi: "two heads",
+                        harpoontop: "harpoon",
+                        harpoonbottom: "harpoon'",
+                    }));
+                    if (Object.keys(style).length === 0) {
+                        delete delta.style;
+                    }
+                }
+
+                let statement = `${names.get(edge.source)} -> ${names.get(edge.target)}`;
+                if (quiver.dependencies_of(edge).size > 0) {
+                    statement = `${name_cell(edge, "e")} = ${statement}`;
+                }
+                if (edge.label !== "") {
+                    statement += ` : ${quote(edge.label)}`;
+                }
+                lines.push(`${statement}${format_attributes(attributes, delta)}`);
+            }
+        }
+
+        return {
+            data: `quiver {\n${lines.map((line) => `    ${line}`).join("\n")}${
+                lines.length > 0 ? "\n" : ""
+            }}`,
+            metadata: {},
+        };
+    }
+
+    /// Imports a diagram in the text format into the existing diagram, returning the new cells (as
+    /// `data`). The text is parsed in its entirety before any cells are created, so that a syntax
+    /// error does not leave a partially imported diagram. Errors are reported with their line and
+    /// column.
+    import(ui, string) {
+        // Split the text into tokens, recording the position of each.
+        const tokens = [];
+        const TOKENS = [
+            ["whitespace", /[ \t\r]+|#[^\n]*/y],
+            ["newline", /\n/y],
+            ["identifier", /[a-zA-Z_][a-zA-Z0-9_']*/y],
+            ["number", /-?(?:\d+\.?\d*|\.\d+)/y],
+            ["string", /"((?:[^"]|"")*)"/y],
+            ["punctuation", /->|[{}[\]&:=,]/y],
+        ];
+        let [line, column] = [1, 1];
+        const error = (message, token = { line, column }) => {
+            throw new Error(`line ${token.line}, column ${token.column}: ${message}`);
+        };
+        // Advances the line and column past `text`.
+        const advance = (text) => {
+            const lines = text.split("\n");
+            line += lines.length - 1;
+            column = lines.length > 1 ? lines[lines.length - 1].length + 1
+                : column + text.length;
+        };
+        for (let i = 0; i < string.length;) {
+            let matched = false;
+            for (const [kind, regex] of TOKENS) {
+                regex.lastIndex = i;
+                const match = regex.exec(string);
+                if (match === null) {
+                    continue;
+                }
+                // The `{` following `quiver` may be on a later line, so we skip newlines to find
+                // the previous token.
+                let previous = tokens.length - 1;
+                while (previous >= 0 && tokens[previous].kind === "punctuation"
+                    && tokens[previous].value === "\n") {
+                    --previous;
+                }
+                if (kind === "punctuation" && match[0] === "{" && previous >= 0
+                    && tokens[previous].value !== "quiver") {
+                    // A `{` other than that following `quiver` begins a JSON object, which we
+                    // find the end of by matching brackets.
+                    let depth = 0;
+                    const JSON_TOKEN = /"(?:[^"\\]|\\.)*"|[^"{}]+|[{}]/y;
+                    let j = i;
+                    do {
+                        JSON_TOKEN.lastIndex = j;
+                        const part = JSON_TOKEN.exec(string);
+                        if (part === null) {
+                            break;
+                        }
+                        depth += part[0] === "{" ? 1 : part[0] === "}" ? -1 : 0;
+                        j = JSON_TOKEN.lastIndex;
+                    } while (depth > 0 && j < string.length);
+                    const text = string.slice(i, j);
+                    let value;
+                    try {
+                        value = JSON.parse(text);
+                    } catch (_) {
+                        error("invalid JSON options");
+                    }
+                    tokens.push({ kind: "json", value, line, column });
+                    advance(text);
+                    i = j;
+                    matched = true;
+                    break;
+                }
+                if (kind !== "whitespace") {
+                    tokens.push({
+                        kind: kind === "newline" ? "punctuation" : kind,
+                        value: kind === "string" ? match[1].replace(/""/g, "\"") : match[0],
+                        line,
+                        column,
+                    });
+                }
+                advance(match[0]);
+                i = regex.lastIndex;
+                matched = true;
+                break;
+            }
+            if (!matched) {
+                error(string[i] === "\"" ? "unterminated label" :
+                    `unexpected character \`${string[i]}\``);
+            }
+        }
+        tokens.push({ kind: "end", value: "", line, column });
+
+        let t = 0;
+        const peek = () => tokens[t];
+        const eat = (value) => {
+            if (peek().value === value && peek().kind !== "string") {
+                return tokens[t++];
+            }
+            return null;
+        };
+        const expect = (value, description = `\`${value}\``) => {
+            const token = eat(value);
+            if (token === null) {
+                error(`expected ${description}`, peek());
+            }
+            return token;
+        };
+        const expect_kind = (kind, description) => {
+            if (peek().kind !== kind) {
+                error(`expected ${description}`, peek());
+            }
+            return tokens[t++];
+        };
+        const skip_newlines = () => {
+            while (eat("\n") !== null);
+        };
+
+        // Parses an optional attribute list, returning a list of `[key, value, token]` triples.
+        // Keys may contain spaces, like `maps to`. JSON options have the key `null`.
+        const parse_attributes = () => {
+            const attributes = [];
+            if (eat("[") === null) {
+                return attributes;
+            }
+            while (eat("]") === null) {
+                const token = peek();
+                if (token.kind === "json") {
+                    if (typeof token.value !== "object" || Array.isArray(token.value)) {
+                        error("expected a JSON object", token);
+                    }
+                    attributes.push([null, tokens[t++].value, token]);
+                } else {
+                    const words = [expect_kind("identifier", "an attribute").value];
+                    while (peek().kind === "identifier") {
+                        words.push(tokens[t++].value);
+                    }
+                    let value = null;
+                    if (eat("=") !== null) {
+                        const value_token = peek();
+                        if (value_token.kind !== "number" && value_token.kind !== "identifier") {
+                            error("expected an attribute value", value_token);
+                        }
+                        value = tokens[t++].value;
+                    }
+                    attributes.push([words.join(" "), value, token]);
+                }
+                if (eat(",") === null) {
+                    expect("]", "`,` or `]`");
+                    break;
+                }
+            }
+            return attributes;
+        };
+
+        // Returns the numeric value of an attribute, checking it is an integer within range.
+        const integer = ([key, value, token], min = -Infinity, max = Infinity) => {
+            const number = Number(value);
+            if (value === null || !Number.isInteger(number) || number < min || number > max) {
+                error(`invalid value for \`${key}\``, token);
+            }
+            return number;
+        };
+        // Returns whether `value` is a JSON object (rather than an array or primitive).
+        const is_object = (value) => {
+            return typeof value === "object" && value !== null && !Array.isArray(value);
+        };
+        // The valid names of each component of an arrow style. The hook and harpoon components
+        // must also have a `side`.
+        const STYLE_NAMES = {
+            name: ["arrow", "adjunction", "corner", "corner-inverse"],
+            tail: ["none", "maps to", "mono", "hook", "arrowhead"],
+            body: ["cell", "none", "dashed", "dotted", "squiggly", "barred"],
+            head: ["arrowhead", "none", "epi", "harpoon"],
+        };
+        // Returns whether `value` is a valid style, or part of one, e.g. `{"tail": {"name": "hook",
+        // "side": "top"}}`.
+        const is_style = (value) => {
+            return is_object(value) && Object.entries(value).every(([component, setting]) => {
+                if (component === "name") {
+                    return STYLE_NAMES.name.includes(setting);
+                }
+                if (!STYLE_NAMES.hasOwnProperty(component) || !is_object(setting)
+                    || !STYLE_NAMES[component].includes(setting.name)) {
+                    return false;
+                }
+                const { name, ...rest } = setting;
+                return ["hook", "harpoon"].includes(name) ?
+                    ["top", "bottom"].includes(rest.side) && Object.keys(rest).length === 1
+                    : Object.keys(rest).length === 0;
+            });
+        };
+        // Returns whether `value` is a valid colour, in HSLA format, as in the base64 format.
+        const is_colour = (value) => {
+            if (!Array.isArray(value) || value.length < 3 || value.length > 4) {
+                return false;
+            }
+            const [h, s, l, a = 1] = value;
+            return [[h, 360], [s, 100], [l, 100]].every(([x, max]) => {
+                return Number.isInteger(x) && x >= 0 && x <= max;
+            }) && typeof a === "number" && a >= 0 && a <= 1;
+        };
+        // Separates JSON options from named attributes, converting colours to `Colour`s. The
+        // options are checked with the same constraints as the named attributes, so that any
+        // diagram that is imported may be exported again. The `style` and `shorten` options may be
+        // given in parts, which are merged.
+        const json_options = (attributes, cell) => {
+            const options = {};
+            for (const [key, value, token] of attributes) {
+                if (key === null) {
+                    for (const [name, option] of Object.entries(value)) {
+                        // Whether `option` is an integer within the given range.
+                        const integral = (min = -Infinity, max = Infinity) => {
+                            return Number.isInteger(option) && option >= min && option <= max;
+                        };
+                        let valid;
+                        switch (name) {
+                            case "colour":
+                            case "label_colour":
+                                valid = is_colour(option);
+                                break;
+                            case "label_alignment":
+                                valid = ["left", "centre", "right", "over"].includes(option);
+                                break;
+                            case "label_position":
+                                valid = integral(0, 100);
+                                break;
+                            case "offset":
+                            case "curve":
+                                valid = integral();
+                                break;
+                            case "level":
+                                valid = integral(1);
+                                break;
+                            case "style":
+                                valid = is_style(option);
+                                break;
+                            case "shorten":
+                                valid = is_object(option)
+                                    && Object.entries(option).every(([end, shorten]) => {
+                                        return ["source", "target"].includes(end)
+                                            && typeof shorten === "number"
+                                            && shorten >= 0 && shorten <= 100;
+                                    });
+                                break;
+                            default:
+                                valid = true;
+                                break;
+                        }
+                        if (!valid) {
+                            error(`invalid value for \`${name}\``, token);
+                        }
+                        switch (name) {
+                            case "colour":
+                            case "label_colour":
+                                cell[name] = new Colour(...option);
+                                break;
+                            case "style":
+                            case "shorten":
+                                options[name] = Object.assign(options[name] || {}, option);
+                                break;
+                            default:
+                                options[name] = option;
+                                break;
+                        }
+                    }
+                }
+            }
+            return options;
+        };
+
+        // Parse `quiver { ... }`.
+        skip_newlines();
+        expect("quiver");
+        skip_newlines();
+        expect("{");
+
+        // Objects are collected from the rows, and arrows from the arrow statements. Arrows are
+        // resolved once all the objects are known.
+        const objects = new Map();
+        const positions = new Map();
+        const arrows = [];
+        let row = 0;
+        for (skip_newlines(); eat("}") === null; skip_newlines()) {
+            if (peek().kind === "end") {
+                error("expected `}`", peek());
+            }
+
+            // An arrow statement contains `->` before the end of the line.
+            let is_arrow = false;
+            for (let u = t; tokens[u].value !== "\n" && tokens[u].kind !== "end"; ++u) {
+                if (tokens[u].value === "->" && tokens[u].kind === "punctuation") {
+                    is_arrow = true;
+                    break;
+                }
+            }
+
+            if (is_arrow) {
+                const arrow = {};
+                let endpoint = expect_kind("identifier", "a name");
+                if (eat("=") !== null) {
+                    arrow.name = endpoint;
+                    endpoint = expect_kind("identifier", "a name");
+                }
+                arrow.source = endpoint;
+                arrow.token = expect("->");
+                arrow.target = expect_kind("identifier", "a name");
+                arrow.label = "";
+                if (eat(":") !== null) {
+                    arrow.label = expect_kind("string", "a label").value;
+                }
+                arrow.attributes = parse_attributes();
+                arrows.push(arrow);
+            } else {
+                let x = 0;
+                while (true) {
+                    if (peek().kind === "identifier") {
+                        const name = tokens[t++];
+                        const object = { label: name.value, position: new Position(x, row) };
+                        if (eat(":") !== null) {
+                            object.label = expect_kind("string", "a label").value;
+                        }
+                        const attributes = parse_attributes();
+                        let [object_x, object_y] = [x, row];
+                        for (const attribute of attributes) {
+                            switch (attribute[0]) {
+                                case "x":
+                                    object_x = integer(attribute, 0);
+                                    break;
+                                case "y":
+                                    object_y = integer(attribute, 0);
+                                    break;
+                                case null:
+                                    break;
+                                default:
+                                    error(`unknown object attribute \`${attribute[0]}\``,
+                                        attribute[2]);
+                            }
+                        }
+                        object.position = new Position(object_x, object_y);
+                        json_options(attributes, object);
+                        if (objects.has(name.value)) {
+                            error(`\`${name.value}\` has already been declared`, name);
+                        }
+                        if (positions.has(`${object.position}`)) {
+                            error(`\`${name.value}\` is in the same position as \`${
+                                positions.get(`${object.position}`)
+                            }\``, name);
+                        }
+                        objects.set(name.value, object);
+                        positions.set(`${object.position}`, name.value);
+                    }
+                    if (eat("&") === null) {
+                        break;
+                    }
+                    ++x;
+                }
+                ++row;
+            }
+            if (eat("\n") === null && peek().value !== "}") {
+                error("expected the end of the line", peek());
+            }
+        }
+        skip_newlines();
+        if (peek().kind !== "end") {
+            error("unexpected text after the diagram", peek());
+        }
+
+        // Resolve the arrows, computing their options.
+        const named_arrows = new Map();
+        for (const arrow of arrows) {
+            for (const endpoint of ["source", "target"]) {
+                const name = arrow[endpoint];
+                if (objects.has(name.value)) {
+                    arrow[endpoint] = objects.get(name.value);
+                } else if (named_arrows.has(name.value)) {
+                    arrow[endpoint] = named_arrows.get(name.value);
+                } else if (arrows.some((other) => other.name && other.name.value === name.value)) {
+                    error(`\`${name.value}\` must be declared before it is used`, name);
+                } else {
+                    error(`\`${name.value}\` has not been declared`, name);
+                }
+            }
+            if (arrow.source === arrow.target) {
+                error("quiver does not support loops", arrow.token);
+            }
+            if (typeof arrow.name !== "undefined") {
+                if (objects.has(arrow.name.value) || named_arrows.has(arrow.name.value)) {
+                    error(`\`${arrow.name.value}\` has already been declared`, arrow.name);
+                }
+                named_arrows.set(arrow.name.value, arrow);
+            }
+            arrow.level = Math.max(arrow.source.level || 0, arrow.target.level || 0) + 1;
+
+            const options = { shorten: {} };
+            const style = {};
+            // The attributes that set a component of the style.
+            const STYLES = new Map([
+                ["adjunction", ["name", "adjunction"]],
+                ["corner", ["name", "corner"]],
+                ["corner inverse", ["name", "corner-inverse"]],
+                ["maps to", ["tail", { name: "maps to" }]],
+                ["tail", ["tail", { name: "mono" }]],
+                ["hook", ["tail", { name: "hook", side: "top" }]],
+                ["hook'", ["tail", { name: "hook", side: "bottom" }]],
+                ["tail reversed", ["tail", { name: "arrowhead" }]],
+                ["dashed", ["body", { name: "dashed" }]],
+                ["dotted", ["body", { name: "dotted" }]],
+                ["squiggly", ["body", { name: "squiggly" }]],
+                ["barred", ["body", { name: "barred" }]],
+                ["no body", ["body", { name: "none" }]],
+                ["no head", ["head", { name: "none" }]],
+                ["two heads", ["head", { name: "epi" }]],
+                ["harpoon", ["head", { name: "harpoon", side: "top" }]],
+                ["harpoon'", ["head", { name: "harpoon", side: "bottom" }]],
+            ]);
+            for (const attribute of arrow.attributes) {
+                const [key, value, token] = attribute;
+                if (STYLES.has(key)) {
+                    const [component, setting] = STYLES.get(key);
+                    style[component] = setting;
+                    continue;
+                }
+                switch (key) {
+                    case "swap":
+                        options.label_alignment = "right";
+                        break;
+                    case "description":
+                        options.label_alignment = "centre";
+                        break;
+                    case "marking":
+                        options.label_alignment = "over";
+                        break;
+                    case "pos":
+                        const position = Math.round(Number(value) * 100);
+                        if (value === null || !(position >= 0 && position <= 100)) {
+                            error("invalid value for `pos`", token);
+                        }
+                        options.label_position = position;
+                        break;
+                    case "offset":
+                    case "curve":
+                        options[key] = integer(attribute);
+                        break;
+                    case "level":
+                        options.level = integer(attribute, 1);
+                        break;
+                    case "shorten source":
+                    case "shorten target":
+                        options.shorten[key.split(" ")[1]] = integer(attribute, 0, 100);
+                        break;
+                    case null:
+                        break;
+                    default:
+                        error(`unknown arrow attribute \`${key}\``, token);
+                }
+            }
+            // Named attributes take precedence over JSON options. The JSON options are merged with
+            // the named attributes component by component, so that, for instance, one end of the
+            // arrow may be shortened by a named attribute and the other by a JSON option.
+            const {
+                style: json_style = {},
+                shorten: json_shorten = {},
+                ...json
+            } = json_options(arrow.attributes, arrow);
+            const { source = 0, target = 0 } = Object.assign(json_shorten, options.shorten);
+            if (source + target > 100) {
+                error("the arrow is shortened by more than 100%", arrow.token);
+            }
+            options.shorten = { source, target };
+            if (arrow.hasOwnProperty("colour")) {
+                options.colour = arrow.colour;
+            }
+            arrow.options = Edge.default_options(
+                Object.assign({ level: arrow.level }, json, options),
+                Object.assign(json_style, style),
+            );
+        }
+
+        // Create the cells, placing the diagram at the focus point, as for the other formats.
+        let offset = new Position(Infinity, Infinity);
+        for (const object of objects.values()) {
+            offset = offset.min(object.position);
+        }
+        const relative = Array.from(objects.values()).map((object) => {
+            return object.position.sub(offset);
+        });
+        const origin = QuiverImportExport.import_origin(ui, relative);
+
+        ui.buffer_updates = true;
+        const cells = [];
+        for (const object of objects.values()) {
+            object.cell = new Vertex(
+                ui,
+                object.label,
+                origin.add(object.position.sub(offset)),
+                object.label_colour || Colour.black(),
+            );
+            cells.push(object.cell);
+        }
+        for (const arrow of arrows) {
+            arrow.cell = new Edge(
+                ui,
+                arrow.label,
+                arrow.source.cell,
+                arrow.target.cell,
+                arrow.options,
+                arrow.label_colour || Colour.black(),
+            );
+            cells.push(arrow.cell);
+        }
+
+        QuiverImportExport.complete_import(ui, cells);
+
+        return {
+            data: cells,
+            metadata: {},
+        };
+    }
+};
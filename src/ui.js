@@ -39,6 +39,9 @@ Object.assign(CONSTANTS, {
     },
     /// How many pixels to leave around the border of an embedded diagram.
     EMBED_PADDING: 24,
+    /// The number of rows or columns beyond which we warn the user that an imported diagram is
+    /// likely to be too large to be usable.
+    IMPORT_SIZE_WARNING: 32,
 });
 
 /// Various states for the UI (e.g. whether cells are being rearranged, or connected, etc.).
@@ -835,6 +838,15 @@ class UI {
             .add(new DOM.Element("h2").add("Export"))
             .add(new DOM.Table([
                 ["Toggle diagram centring", (td) => Shortcuts.element(td, [{ key: "C" }])]
+            ]))
+            .add(new DOM.Element("h2").add("Import"))
+            .add(new DOM.Table([
+                ["Paste tikz-cd code, DOT graph or quiver text", (td) => Shortcuts.element(td, [
+                    { key: "V", modifier: true }
+                ])],
+                ["Open pasted diagram link", (td) => Shortcuts.element(td, [
+                    { key: "V", modifier: true }
+                ])],
             ])));
 
         // Set up the "About" pane.
@@ -1501,6 +1513,47 @@ class UI {
             }
         });
 
+        // Pasting tikz-cd code, a DOT graph, or a diagram in the text format into the editor
+        // imports the diagram.
+        document.addEventListener("paste", (event) => {
+            if (!this.in_mode(UIMode.Default) || this.input_is_active()) {
+                return;
+            }
+            const data = event.clipboardData.getData("text/plain");
+            if (/\\begin\s*\{tikzcd\}/.test(data)) {
+                event.preventDefault();
+                this.import("tikz-cd", data);
+            } else if (
+                /^(?:\s|\/\/[^\n]*|\/\*[^]*?\*\/|#[^\n]*)*(?:strict\s+)?(?:di)?graph\b[^{]*\{/i
+                    .test(data)
+            ) {
+                event.preventDefault();
+                this.import("dot", data);
+            } else if (/^(?:\s|#[^\n]*)*quiver\s*\{/.test(data)) {
+                event.preventDefault();
+                this.import("text", data);
+            } else if (this.is_diagram_url(data.trim())) {
+                // Pasting a link to a diagram offers to open it: in this tab if the current
+                // diagram is empty, so that no work is lost, and in a new one otherwise.
+                event.preventDefault();
+                // quiver only loads diagrams from the query string, so we move a diagram given in
+                // the fragment into the query string. This also ensures the page is reloaded, which
+                // would not happen if only the fragment changed.
+                const url = new URL(data.trim());
+                if (!url.searchParams.has("q")) {
+                    url.search = url.hash.slice(1);
+                    url.hash = "";
+                }
+                if (this.quiver.is_empty()) {
+                    if (window.confirm("Open the diagram from the pasted link?")) {
+                        window.location.href = url.href;
+                    }
+                } else if (window.confirm("Open the diagram from the pasted link in a new tab?")) {
+                    window.open(url.href, "_blank");
+                }
+            }
+        });
+
         // Add various keyboard shortcuts.
 
         this.shortcuts.add([{ key: "Enter", context: Shortcuts.SHORTCUT_PRIORITY.Always }], () => {
@@ -2661,6 +2714,98 @@ class UI {
         this.pan_view(min_offset.add(max_offset).div(2));
     }
 
+    /// Returns whether a string is a link to a diagram, either on q.uiver.app or on the instance of
+    /// quiver that is currently running.
+    is_diagram_url(string) {
+        let url;
+        try {
+            url = new URL(string);
+        } catch (_) {
+            return false;
+        }
+        const current = new URL(window.location.href);
+        const same_instance = url.origin === current.origin && url.pathname === current.pathname;
+        // Diagrams may be given either in the query string, or in the fragment, as in
+        // `https://q.uiver.app/#q=...`.
+        return (url.hostname === "q.uiver.app" || same_instance)
+            && (url.searchParams.has("q") || new URLSearchParams(url.hash.slice(1)).has("q"));
+    }
+
+    /// Imports code in a textual format (e.g. that has been pasted into the editor) into the
+    /// diagram, and selects the imported cells. Currently, the supported formats are:
+    /// - "tikz-cd"
+    /// - "dot"
+    /// - "text"
+    import(format, string) {
+        const description = {
+            "tikz-cd": "tikz-cd code",
+            dot: "DOT graph",
+            text: "quiver text",
+        }[format];
+        let cells, incompatibilities;
+        try {
+            switch (format) {
+                case "tikz-cd":
+                    ({ data: cells, metadata: { tikz_incompatibilities: incompatibilities } }
+                        = QuiverImportExport.tikz_cd.import(this, string));
+                    break;
+                case "dot":
+                    ({ data: cells, metadata: { dot_incompatibilities: incompatibilities } }
+                        = QuiverImportExport.dot.import(this, string));
+                    break;
+                case "text":
+                    // The text format can represent any diagram, so there are never any
+                    // incompatibilities.
+                    ({ data: cells } = QuiverImportExport.text.import(this, string));
+                    incompatibilities = new Set();
+                    break;
+                default:
+                    throw new Error(`unknown import format \`${format}\``);
+            }
+        } catch (error) {
+            UI.display_error(`The ${description} could not be imported: ${error.message}.`);
+            return;
+        }
+
+        if (cells.length > 0) {
+            this.deselect();
+            this.select(...cells);
+            this.history.add(this, [{
+                kind: "create",
+                cells: new Set(cells),
+            }]);
+            this.centre_view();
+        }
+
+        // Only one error banner is displayed at a time, so we combine any warnings.
+        const warnings = [];
+        // As with export, we let the user know if parts of the diagram were not imported.
+        if (incompatibilities.size > 0) {
+            warnings.push(
+                `Some parts of the ${description} are not supported by quiver, and have been ` +
+                `ignored: ${Array.from(incompatibilities).join(", ")}.`
+            );
+        }
+        // Generated diagrams in particular can be too large to be practical to edit.
+        const vertices = cells.filter((cell) => cell.is_vertex());
+        if (vertices.length > 0) {
+            const extent = (axis) => {
+                const coordinates = vertices.map((vertex) => vertex.position[axis]);
+                return Math.max(...coordinates) - Math.min(...coordinates) + 1;
+            };
+            const [width, height] = [extent("x"), extent("y")];
+            if (Math.max(width, height) > CONSTANTS.IMPORT_SIZE_WARNING) {
+                warnings.push(
+                    `The imported diagram is very large (${width} × ${height} cells), and may ` +
+                    "be difficult to work with."
+                );
+            }
+        }
+        if (warnings.length > 0) {
+            UI.display_error(warnings.join(" "));
+        }
+    }
+
     /// Returns a unique identifier for an object.
     unique_id(object) {
         if (!this.ids.has(object)) {
@@ -3511,6 +3656,8 @@ class Settings {
             "export.embed.width": CONSTANTS.DEFAULT_EMBED_SIZE.WIDTH,
             // The height of an HTML embedded diagram in pixels.
             "export.embed.height": CONSTANTS.DEFAULT_EMBED_SIZE.HEIGHT,
+            // Whether to convert labels to plain text in DOT output, rather than keeping the LaTeX.
+            "export.dot.strip_math": false,
             // Which variant of the corner to use for pullbacks/pushouts.
             "diagram.var_corner": false,
         };
@@ -4247,7 +4394,8 @@ class Panel {
                     ui.definitions(),
                 ));
 
-                let export_pane, tip, warning, list, latex_options, embed_options, content;
+                let export_pane, tip, warning, description, list, latex_options, embed_options,
+                    dot_options, content;
 
                 // Select the code for easy copying.
                 const select_output = () => {
@@ -4331,9 +4479,7 @@ class Panel {
                         .add_to(export_pane);
 
                     warning = new DOM.Element("span", { class: "warning hidden" })
-                        .add("The exported tikz-cd diagram may not match the quiver diagram " +
-                            "exactly, as tikz-cd does not support the following features that " +
-                            "appear in this diagram:")
+                        .add(description = new DOM.Element("span"))
                         .add(list = new DOM.Element("ul"))
                         .add_to(export_pane);
 
@@ -4365,6 +4511,17 @@ class Panel {
                         .add(new DOM.Element("label").add("Height: ").add(embed_size.height))
                         .add_to(export_pane)
 
+                    const strip_math_checkbox = new DOM.Element("input", {
+                        type: "checkbox",
+                        "data-setting": "export.dot.strip_math",
+                    });
+                    dot_options = new DOM.Div({ class: "options dot hidden" })
+                        .add(new DOM.Element("label")
+                            .add(strip_math_checkbox)
+                            .add("Strip LaTeX from labels")
+                        )
+                        .add_to(export_pane);
+
                     // When the shortcut is active, we will always be displaying the modal pane,
                     // so the shortcut is always valid.
                     const shortcut = { key: "C", context: Shortcuts.SHORTCUT_PRIORITY.Always };
@@ -4380,6 +4537,7 @@ class Panel {
                     const checkboxes = [
                         [centre_checkbox, "tikz-cd"],
                         [fixed_size_checkbox, "html"],
+                        [strip_math_checkbox, "dot"],
                     ];
                     for (const [checkbox, format] of checkboxes) {
                         checkbox.listen("change", () => {
@@ -4439,15 +4597,31 @@ class Panel {
                     export_pane = ui.element.query_selector(".export");
                     tip = export_pane.query_selector(".tip");
                     warning = export_pane.query_selector(".warning");
+                    description = warning.query_selector("span");
                     list = export_pane.query_selector("ul");
                     latex_options = export_pane.query_selector(".options.latex");
                     embed_options = export_pane.query_selector(".options.embed");
+                    dot_options = export_pane.query_selector(".options.dot");
                     content = export_pane.query_selector(".code");
                 }
                 // Display a warning if necessary.
                 list.clear();
-                const unsupported_items = format === "tikz-cd" ?
-                    Array.from(metadata.tikz_incompatibilities).sort() : [];
+                // The formats that may not be able to represent every diagram, along with the
+                // features of the diagram that could not be represented.
+                const incompatibilities = new Map([
+                    ["tikz-cd", ["tikz-cd", metadata.tikz_incompatibilities]],
+                    ["dot", ["DOT", metadata.dot_incompatibilities]],
+                    ["typst", ["fletcher", metadata.typst_incompatibilities]],
+                    ["xypic", ["xy-pic", metadata.xypic_incompatibilities]],
+                ]);
+                let unsupported_items = [];
+                if (incompatibilities.has(format)) {
+                    const [name, items] = incompatibilities.get(format);
+                    description.clear().add(`The exported ${name} diagram may not match the ` +
+                        `quiver diagram exactly, as ${name} does not support the following ` +
+                        "features that appear in this diagram:");
+                    unsupported_items = Array.from(items).sort();
+                }
                 for (const [index, item] of unsupported_items.entries()) {
                     list.add(new DOM.Element("li")
                         .add(`${item}${index + 1 < unsupported_items.length ? ";" : "."}`)
@@ -4457,6 +4631,7 @@ class Panel {
                 warning.class_list.toggle("hidden", unsupported_items.length === 0);
                 latex_options.class_list.toggle("hidden", format !== "tikz-cd");
                 embed_options.class_list.toggle("hidden", format !== "html");
+                dot_options.class_list.toggle("hidden", format !== "dot");
 
                 for (const checkbox of export_pane.query_selector_all('input[type="checkbox"]')) {
                     if (ui.settings.get(checkbox.get_attribute("data-setting"))) {
@@ -4504,6 +4679,36 @@ class Panel {
               .listen("click", () => {
                   display_export_pane("html");
               })
+        ).add(
+            // The Graphviz export button.
+            new DOM.Element("button").add("DOT")
+                .listen("click", () => {
+                    display_export_pane("dot");
+                })
+        ).add(
+            // The Typst export button.
+            new DOM.Element("button").add("Typst")
+                .listen("click", () => {
+                    display_export_pane("typst");
+                })
+        ).add(
+            // The xy-pic export button.
+            new DOM.Element("button").add("xy-pic")
+                .listen("click", () => {
+                    display_export_pane("xypic");
+                })
+        ).add(
+            // The text export button.
+            new DOM.Element("button").add("Text")
+                .listen("click", () => {
+                    display_export_pane("text");
+                })
+        ).add(
+            // The alternative text export button.
+            new DOM.Element("button").add("Alt text")
+                .listen("click", () => {
+                    display_export_pane("alt-text");
+                })
         ).add(export_to_latex).add(
             new DOM.Div({ class: "indicator-container" }).add(
                 new DOM.Element("label").add("Macros: ")
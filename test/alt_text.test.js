@@ -0,0 +1,16 @@
+// Golden-file tests for exporting alternative text. The description of each diagram in the corpus
+// is compared against the corresponding file in `golden/alt-text`, to lock down the wording.
+
+const assert = require("assert");
+const path = require("path");
+const test = require("node:test");
+const { export_diagram, diagrams, import_corpus, golden } = require("./harness.js");
+
+for (const file of diagrams()) {
+    test(`alt text export: ${file}`, () => {
+        const { ui } = import_corpus(file);
+        const alt_text = export_diagram(ui, "alt-text").data;
+        const name = path.basename(file, path.extname(file));
+        assert.strictEqual(alt_text, golden(`alt-text/${name}.txt`, `${alt_text}\n`).trimEnd());
+    });
+}
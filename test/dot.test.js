@@ -0,0 +1,98 @@
+// Tests for exporting and importing Graphviz DOT. The export of each diagram in the corpus is
+// checked to be valid DOT, using Graphviz if it is installed, and otherwise by checking its syntax
+// directly. Importing the export and exporting again must reproduce the same graph.
+
+const assert = require("assert");
+const child_process = require("child_process");
+const test = require("node:test");
+const { import_diagram, export_diagram, diagrams, import_corpus } = require("./harness.js");
+
+// Whether Graphviz is installed.
+const graphviz = child_process.spawnSync("dot", ["-V"]).error === undefined;
+
+// Checks that `dot` is a graph in the subset of DOT that quiver exports: a `digraph` containing
+// node and edge statements, with optional attribute lists, each terminated by `;`.
+const check_syntax = (dot) => {
+    const TOKEN = /\s+|\/\/[^\n]*|"(?:[^"\\]|\\.)*"|[a-zA-Z_][a-zA-Z0-9_]*|-?\d+|->|[[\]{}=,;]/y;
+    const tokens = [];
+    for (let i = 0; i < dot.length;) {
+        TOKEN.lastIndex = i;
+        const match = TOKEN.exec(dot);
+        assert.notStrictEqual(match, null, `unexpected character at ${i}: ${dot.slice(i, i + 20)}`);
+        if (!/^(?:\s|\/\/)/.test(match[0])) {
+            tokens.push(match[0]);
+        }
+        i = TOKEN.lastIndex;
+    }
+
+    let t = 0;
+    const expect = (token) => assert.strictEqual(tokens[t++], token);
+    const id = () => assert.match(tokens[t++], /^(?:[a-zA-Z_][a-zA-Z0-9_]*|-?\d+|".*")$/s);
+    const attributes = () => {
+        if (tokens[t] !== "[") {
+            return;
+        }
+        for (++t; tokens[t] !== "]"; tokens[t] === "," && ++t) {
+            id();
+            expect("=");
+            id();
+        }
+        expect("]");
+    };
+
+    expect("digraph");
+    expect("{");
+    while (tokens[t] !== "}") {
+        assert.ok(t < tokens.length, "expected `}`");
+        id();
+        if (tokens[t] === "->") {
+            ++t;
+            id();
+        }
+        attributes();
+        expect(";");
+    }
+    expect("}");
+    assert.strictEqual(t, tokens.length);
+};
+
+// The export without the base64 encoding of the diagram in its first line, which would change if
+// any arrows had to be omitted (e.g. arrows between arrows).
+const graph = (dot) => dot.replace(/^\/\/.*\n/, "");
+
+for (const file of diagrams()) {
+    test(`DOT export is valid: ${file}`, () => {
+        const { ui } = import_corpus(file);
+        for (const strip_math of [false, true]) {
+            const dot = export_diagram(ui, "dot", { "export.dot.strip_math": strip_math }).data;
+            check_syntax(dot);
+            if (graphviz) {
+                const result = child_process.spawnSync("dot", ["-Tsvg"], { input: dot });
+                assert.strictEqual(result.status, 0, result.stderr.toString());
+                assert.match(result.stdout.toString(), /<svg/);
+            }
+        }
+    });
+
+    test(`DOT round trip: ${file}`, () => {
+        const { ui } = import_corpus(file);
+        const dot = export_diagram(ui, "dot").data;
+        const reimported = import_diagram("dot", dot);
+        assert.deepStrictEqual([...reimported.result.metadata.dot_incompatibilities], []);
+        assert.strictEqual(graph(export_diagram(reimported.ui, "dot").data), graph(dot));
+    });
+}
+
+test("DOT export: fractions in plain text labels", () => {
+    const { ui } = import_diagram("text", String.raw`quiver {
+        A : "\frac{a}{b}" & B : "\frac{\mathbf{x} + 1}{\alpha}" & C : "\binom{n}{k}"
+    }`);
+    const result = export_diagram(ui, "dot", { "export.dot.strip_math": true });
+    assert.match(result.data, /label="a\/b"/);
+    assert.match(result.data, /label="\(x \+ 1\)\/α"/);
+    assert.match(result.data, /label="\\\\binom\{n\}\{k\}"/);
+    assert.deepStrictEqual(
+        [...result.metadata.dot_incompatibilities],
+        ["the `\\binom` command in plain text labels"],
+    );
+});
@@ -0,0 +1,14 @@
+// https://q.uiver.app/?q=WzAsMyxbMCwwLCJcXG1hdGhjYWx7Q30iXSxbMSwwLCJcXG1hdGhjYWx7RH0iXSxbMSwxLCJYXzFee29wfSJdLFswLDEsIkYiLDAseyJjdXJ2ZSI6Mn1dLFsxLDAsIkciLDAseyJjdXJ2ZSI6Mn1dLFswLDIsIlxcZXRhIiwwLHsic3R5bGUiOnsidGFpbCI6eyJuYW1lIjoiaG9vayIsInNpZGUiOiJ0b3AifSwiYm9keSI6eyJuYW1lIjoiZGFzaGVkIn0sImhlYWQiOnsibmFtZSI6ImVwaSJ9fX1dLFsyLDAsIlxcd2VpcmR7eH0iLDAseyJzdHlsZSI6eyJib2R5Ijp7Im5hbWUiOiJub25lIn0sImhlYWQiOnsibmFtZSI6Im5vbmUifX19XSxbMiwxLCJHIFxcY2lyYyBGIiwwLHsic3R5bGUiOnsibmFtZSI6ImNvcm5lciJ9fV0sWzMsNCwiIiwwLHsic3R5bGUiOnsibmFtZSI6ImFkanVuY3Rpb24ifX1dXQ==
+#import "@preview/fletcher:0.5.8" as fletcher: diagram, node, edge
+#diagram(
+	node((0, 0), $cal(C)$),
+	node((1, 0), $cal(D)$),
+	node((1, 1), $X_1^(o p)$),
+	edge((0, 0), (1, 0), $F$, "->", bend: -56deg),
+	edge((1, 0), (0, 0), $G$, "->", bend: -56deg),
+	edge((0, 0), (1, 1), $eta$, "hook-->>"),
+	// The label `\weird{x}` could not be converted from LaTeX.
+	edge((1, 1), (0, 0), $\weird{x}$, "-", stroke: none),
+	// Omitted X_1^{op} -> \mathcal{D} "G \circ F" (corner): fletcher does not support pullbacks and pushouts.
+	// Omitted (\mathcal{C} -> \mathcal{D} "F") -> (\mathcal{D} -> \mathcal{C} "G") (adjunction): fletcher does not support arrows between arrows.
+)
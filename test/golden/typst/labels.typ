@@ -0,0 +1,16 @@
+// https://q.uiver.app/?q=WzAsMTEsWzAsMCwiXFxtYXRoYmJ7Tn1ee29wfSJdLFsxLDAsIkYoeClfe2ksan0iXSxbMiwwLCJcXGZyYWN7YX17Yn0iXSxbMywwLCJcXHRleHR7U2V0fSJdLFswLDEsIlxcb3BlcmF0b3JuYW1le0hvbX0oQSwgQikiXSxbMSwxLCJcXG15Y21kIFgiXSxbMiwxLCJmJyJdLFszLDEsImEvYiJdLFs0LDEsIlxce3hcXH0iXSxbNSwxLCJYIFxcdGltZXNfWiBZIl0sWzYsMSwiQVxcLEIiXV0=
+#import "@preview/fletcher:0.5.8" as fletcher: diagram, node, edge
+#diagram(
+	node((0, 0), $bb(N)^(o p)$),
+	node((1, 0), $F ( x )_(i , j)$),
+	node((2, 0), $frac(a, b)$),
+	node((3, 0), $"Set"$),
+	node((0, 1), $op("Hom") ( A , B )$),
+	// The label `\mycmd X` could not be converted from LaTeX.
+	node((1, 1), $\mycmd X$),
+	node((2, 1), $f'$),
+	node((3, 1), $a slash b$),
+	node((4, 1), $\{ x \}$),
+	node((5, 1), $X times_Z Y$),
+	node((6, 1), $A thin B$),
+)
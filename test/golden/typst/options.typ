@@ -0,0 +1,13 @@
+// https://q.uiver.app/?q=WzAsNCxbMCwwLCJBIl0sWzEsMCwiQiIsWzEyMCw2MCw2MCwxXV0sWzAsMSwiQyJdLFsyLDEsIkQgXFx0aW1lcyBFIl0sWzAsMSwiZiIsMCx7ImN1cnZlIjotMiwiY29sb3VyIjpbMjQwLDYwLDYwXX1dLFswLDEsImciLDIseyJjdXJ2ZSI6Mn1dLFswLDIsIiIsMCx7InN0eWxlIjp7InRhaWwiOnsibmFtZSI6Imhvb2siLCJzaWRlIjoiYm90dG9tIn0sImJvZHkiOnsibmFtZSI6ImRhc2hlZCJ9fX1dLFsyLDMsImsiLDAseyJsZXZlbCI6Miwic3R5bGUiOnsiaGVhZCI6eyJuYW1lIjoibm9uZSJ9fX1dLFs0LDUsIlxcYWxwaGEiLDAseyJsYWJlbF9wb3NpdGlvbiI6NDAsInNob3J0ZW4iOnsic291cmNlIjoyMCwidGFyZ2V0IjoyMH19XV0=
+#import "@preview/fletcher:0.5.8" as fletcher: diagram, node, edge
+#diagram(
+	node((0, 0), $A$),
+	node((1, 0), text(fill: rgb("#5cd65c"), $B$)),
+	node((0, 1), $C$),
+	node((2, 1), $D times E$),
+	edge((0, 0), (1, 0), $f$, "->", stroke: rgb("#5c5cd6"), bend: 56deg),
+	edge((0, 0), (1, 0), $g$, "->", label-side: right, bend: -56deg),
+	edge((0, 0), (0, 1), "hook'-->"),
+	edge((0, 1), (2, 1), $k$, "-", extrude: (-2, 2)),
+	// Omitted (A -> B "f") -> (A -> B "g") "\alpha": fletcher does not support arrows between arrows.
+)
@@ -0,0 +1,16 @@
+// https://q.uiver.app/?q=WzAsNCxbMCwwLCJBIl0sWzEsMCwiQiJdLFswLDEsIkMiXSxbMSwxLCJEIl0sWzAsMSwiZiJdLFswLDIsImciLDJdLFsxLDMsImgiXSxbMSwyLCJcXHBoaSIsMV0sWzIsMywiayIsMl0sWzIsMSwiIiwwLHsic3R5bGUiOnsiYm9keSI6eyJuYW1lIjoiZGFzaGVkIn19fV0sWzMsMiwiIiwwLHsiY3VydmUiOi0xfV0sWzMsMSwiaCciLDIseyJsYWJlbF9wb3NpdGlvbiI6NzV9XV0=
+#import "@preview/fletcher:0.5.8" as fletcher: diagram, node, edge
+#diagram(
+	node((0, 0), $A$),
+	node((1, 0), $B$),
+	node((0, 1), $C$),
+	node((1, 1), $D$),
+	edge((0, 0), (1, 0), $f$, "->"),
+	edge((0, 0), (0, 1), $g$, "->", label-side: right),
+	edge((1, 0), (1, 1), $h$, "->"),
+	edge((1, 0), (0, 1), $phi.alt$, "->", label-side: center),
+	edge((0, 1), (1, 1), $k$, "->", label-side: right),
+	edge((0, 1), (1, 0), "-->"),
+	edge((1, 1), (0, 1), "->", bend: 37deg),
+	edge((1, 1), (1, 0), $h'$, "->", label-side: right, label-pos: 0.75),
+)
@@ -0,0 +1,10 @@
+// https://q.uiver.app/?q=WzAsMyxbMCwwLCJYIl0sWzIsMCwiWSJdLFswLDEsIloiXSxbMCwxLCJhLCBiIiwwLHsibGFiZWxfcG9zaXRpb24iOjc1LCJjb2xvdXIiOlswLDEwMCw1MF19LFswLDEwMCw1MCwxXV0sWzIsMCwicSIsMCx7fSxbMjQwLDEwMCw1MCwxXV0sWzIsMSwicCJdXQ==
+#import "@preview/fletcher:0.5.8" as fletcher: diagram, node, edge
+#diagram(
+	node((0, 0), $X$),
+	node((2, 0), $Y$),
+	node((0, 1), $Z$),
+	edge((0, 0), (2, 0), text(fill: rgb("#ff0000"), $a , b$), "->", stroke: rgb("#ff0000"), label-pos: 0.75),
+	edge((0, 1), (0, 0), text(fill: rgb("#0000ff"), $q$), "->"),
+	edge((0, 1), (2, 0), $p$, "->"),
+)
@@ -0,0 +1,12 @@
+// https://q.uiver.app/?q=WzAsMyxbMCwwLCJcXG1hdGhjYWx7Q30iXSxbMSwwLCJcXG1hdGhjYWx7RH0iXSxbMiwwLCJcXG1hdGhjYWx7RX0iXSxbMCwxLCJGIiwwLHsiY3VydmUiOi0yfV0sWzAsMSwiRyIsMix7ImN1cnZlIjoyfV0sWzEsMiwiSCIsMCx7Im9mZnNldCI6LTEsInN0eWxlIjp7InRhaWwiOnsibmFtZSI6Im1vbm8ifX19XSxbMSwyLCJLIiwyLHsib2Zmc2V0IjoxLCJzdHlsZSI6eyJoZWFkIjp7Im5hbWUiOiJlcGkifX19XSxbMyw0LCJcXGFscGhhIiwwLHsic2hvcnRlbiI6eyJzb3VyY2UiOjE4LCJ0YXJnZXQiOjE4fX1dXQ==
+#import "@preview/fletcher:0.5.8" as fletcher: diagram, node, edge
+#diagram(
+	node((0, 0), $cal(C)$),
+	node((1, 0), $cal(D)$),
+	node((2, 0), $cal(E)$),
+	edge((0, 0), (1, 0), $F$, "->", bend: 56deg),
+	edge((0, 0), (1, 0), $G$, "->", label-side: right, bend: -56deg),
+	edge((1, 0), (2, 0), $H$, ">->", shift: 3pt),
+	edge((1, 0), (2, 0), $K$, "->>", label-side: right, shift: -3pt),
+	// Omitted (\mathcal{C} -> \mathcal{D} "F") -> (\mathcal{C} -> \mathcal{D} "G") "\alpha": fletcher does not support arrows between arrows.
+)
@@ -0,0 +1,14 @@
+// https://q.uiver.app/?q=WzAsNCxbMCwwLCJBIl0sWzEsMCwiQiJdLFswLDEsIkMiXSxbMSwxLCJEIFxcdGltZXMgRSJdLFswLDEsImYiXSxbMCwyLCJnIiwyLHsic3R5bGUiOnsidGFpbCI6eyJuYW1lIjoiaG9vayIsInNpZGUiOiJ0b3AifX19XSxbMSwzLCJoIiwwLHsic3R5bGUiOnsiYm9keSI6eyJuYW1lIjoiZGFzaGVkIn0sImhlYWQiOnsibmFtZSI6ImVwaSJ9fX1dLFsyLDMsImsiLDIseyJvZmZzZXQiOjJ9XSxbMiwzLCJcXGVsbCIsMCx7ImN1cnZlIjotMX1dLFszLDAsIiIsMCx7InN0eWxlIjp7Im5hbWUiOiJjb3JuZXItaW52ZXJzZSJ9fV1d
+#import "@preview/fletcher:0.5.8" as fletcher: diagram, node, edge
+#diagram(
+	node((0, 0), $A$),
+	node((1, 0), $B$),
+	node((0, 1), $C$),
+	node((1, 1), $D times E$),
+	edge((0, 0), (1, 0), $f$, "->"),
+	edge((0, 0), (0, 1), $g$, "hook->", label-side: right),
+	edge((1, 0), (1, 1), $h$, "-->>"),
+	edge((0, 1), (1, 1), $k$, "->", label-side: right, shift: -6pt),
+	edge((0, 1), (1, 1), $ell$, "->", bend: 37deg),
+	// Omitted D \times E -> A (corner-inverse): fletcher does not support pullbacks and pushouts.
+)
@@ -0,0 +1,19 @@
+// https://q.uiver.app/?q=WzAsNixbMCwwLCJBIl0sWzIsMCwiQiIsWzAsNjAsNjAsMV1dLFsxLDFdLFswLDIsIkMiXSxbMSwyLCJcXGJ1bGxldCJdLFsyLDJdLFswLDEsImYiLDAseyJjdXJ2ZSI6LTJ9XSxbMCwxLCJnIiwyLHsiY3VydmUiOjJ9XSxbMCwzLCJoIiwxLHsibGFiZWxfcG9zaXRpb24iOjMwLCJjb2xvdXIiOlsyNDAsNjAsNjBdLCJzdHlsZSI6eyJ0YWlsIjp7Im5hbWUiOiJtb25vIn19fSxbMjQwLDYwLDYwLDFdXSxbMyw0LCIiLDAseyJjb2xvdXIiOlswLDYwLDYwXSwic3R5bGUiOnsiYm9keSI6eyJuYW1lIjoiYmFycmVkIn0sImhlYWQiOnsibmFtZSI6ImhhcnBvb24iLCJzaWRlIjoiYm90dG9tIn19fV0sWzEsNCwiIiwwLHsic3R5bGUiOnsibmFtZSI6ImFkanVuY3Rpb24ifX1dLFs0LDAsImsiLDMseyJzdHlsZSI6eyJ0YWlsIjp7Im5hbWUiOiJtYXBzIHRvIn0sImhlYWQiOnsibmFtZSI6Im5vbmUifX19XSxbNCwzLCIiLDAseyJsZXZlbCI6Miwic3R5bGUiOnsidGFpbCI6eyJuYW1lIjoiYXJyb3doZWFkIn0sImJvZHkiOnsibmFtZSI6ImRvdHRlZCJ9fX1dLFs0LDEsIiIsMCx7Im9mZnNldCI6LTEsInN0eWxlIjp7InRhaWwiOnsibmFtZSI6Imhvb2siLCJzaWRlIjoiYm90dG9tIn0sImJvZHkiOnsibmFtZSI6InNxdWlnZ2x5In19fV0sWzAsNCwiIiwwLHsic3R5bGUiOnsiYm9keSI6eyJuYW1lIjoibm9uZSJ9LCJoZWFkIjp7Im5hbWUiOiJub25lIn19fV0sWzYsNywiXFxhbHBoYSIsMCx7InNob3J0ZW4iOnsic291cmNlIjo5LCJ0YXJnZXQiOjl9fV1d
+#import "@preview/fletcher:0.5.8" as fletcher: diagram, node, edge
+#diagram(
+	node((0, 0), $A$),
+	node((2, 0), text(fill: rgb("#d65c5c"), $B$)),
+	node((0, 2), $C$),
+	node((1, 2), $bullet$),
+	edge((0, 0), (2, 0), $f$, "->", bend: 37deg),
+	edge((0, 0), (2, 0), $g$, "->", label-side: right, bend: -37deg),
+	edge((0, 0), (0, 2), text(fill: rgb("#5c5cd6"), $h$), ">->", stroke: rgb("#5c5cd6"), label-side: center, label-pos: 0.3),
+	// Approximated C -> \bullet: fletcher does not support barred arrows.
+	edge((0, 2), (1, 2), "-harpoon'", stroke: rgb("#d65c5c")),
+	// Omitted B -> \bullet (adjunction): fletcher does not support adjunctions.
+	edge((1, 2), (0, 0), $k$, "|-", label-side: center, label-fill: false),
+	edge((1, 2), (0, 2), "<..>", extrude: (-2, 2)),
+	edge((1, 2), (2, 0), "hook'~>", shift: 3pt),
+	edge((0, 0), (1, 2), "-", stroke: none),
+	// Omitted (A -> B "f") -> (A -> B "g") "\alpha": fletcher does not support arrows between arrows.
+)
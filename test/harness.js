@@ -0,0 +1,198 @@
+// A harness for testing quiver's import and export formats in Node, without a browser. The source
+// files are loaded into a shared context, in the same order as `index.html`. Cells are replaced by
+// stand-ins that have the same data as `Vertex` and `Edge`, but are not rendered, so that diagrams
+// may be imported and exported without a DOM.
+
+const fs = require("fs");
+const path = require("path");
+const vm = require("vm");
+
+const SOURCE = path.join(__dirname, "..", "src");
+const CORPUS = path.join(__dirname, "corpus");
+const GOLDEN = path.join(__dirname, "golden");
+
+const noop = () => {};
+const context = {
+    console,
+    window: { addEventListener: noop, location: { href: "https://q.uiver.app/" } },
+    document: {
+        documentElement: {},
+        addEventListener: noop,
+        createElement: () => ({}),
+        createElementNS: () => ({ setAttribute: noop }),
+    },
+    navigator: { platform: "Linux", userAgent: "" },
+    setTimeout,
+    clearTimeout,
+    URL,
+    URLSearchParams,
+    btoa,
+    atob,
+    encodeURIComponent,
+};
+context.globalThis = context;
+vm.createContext(context);
+for (const file of ["ds.js", "dom.js", "quiver.js", "bezier.js", "arrow.js", "ui.js"]) {
+    vm.runInContext(fs.readFileSync(path.join(SOURCE, file), "utf8"), context, { filename: file });
+}
+
+vm.runInContext(`
+const RenderedEdge = Edge;
+// An element which permits the methods that are called on elements during import.
+const element = () => ({ query_selector: () => ({ class_list: { remove() {} } }) });
+
+Vertex = class {
+    constructor(ui, label, position, label_colour = Colour.black()) {
+        this.level = 0;
+        this.label = label;
+        this.position = position;
+        this.label_colour = label_colour;
+        this.element = element();
+        ui.quiver.add(this);
+        ui.positions.set(\`\${position}\`, this);
+    }
+
+    is_vertex() {
+        return true;
+    }
+
+    is_edge() {
+        return false;
+    }
+
+    // Cells are not laid out, so we use the grid position in place of the rendered position.
+    centre() {
+        return new Point(this.position.x, this.position.y);
+    }
+};
+
+Edge = class {
+    constructor(ui, label, source, target, options, label_colour = Colour.black()) {
+        this.level = Math.max(source.level, target.level) + 1;
+        this.label = label;
+        this.source = source;
+        this.target = target;
+        this.label_colour = label_colour;
+        this.options = RenderedEdge.default_options(Object.assign({ level: this.level }, options));
+        // The rendered arrow is shortened by a proportion of its length. Without a layout, we
+        // take the length to be the distance between the grid positions of the endpoints, or a
+        // single cell for arrows between arrows, as the tikz-cd import does.
+        const length = (source.is_vertex() && target.is_vertex() ?
+            target.position.sub(source.position).length() : 1) * ui.default_cell_size;
+        this.arrow = {
+            style: {
+                shorten: {
+                    tail: length * this.options.shorten.source / 100,
+                    head: length * this.options.shorten.target / 100,
+                },
+            },
+        };
+        this.element = element();
+        ui.quiver.add(this);
+        ui.quiver.dependencies.get(source).set(this, "source");
+        ui.quiver.dependencies.get(target).set(this, "target");
+        ui.quiver.reverse_dependencies.get(this).add(source);
+        ui.quiver.reverse_dependencies.get(this).add(target);
+    }
+
+    is_vertex() {
+        return false;
+    }
+
+    is_edge() {
+        return true;
+    }
+
+    centre() {
+        return this.source.centre().add(this.target.centre()).div(2);
+    }
+
+    angle() {
+        return this.target.centre().sub(this.source.centre()).angle();
+    }
+
+    static default_options(properties, style) {
+        return RenderedEdge.default_options(properties, style);
+    }
+};
+
+// Top-level classes are not properties of the context, so we expose those that the tests use.
+Object.assign(globalThis, { QuiverExport, QuiverImportExport, Position, Colour });
+
+// The parts of \`UI\` that are used by the import formats.
+globalThis.create_ui = () => ({
+    quiver: new Quiver(),
+    focus_position: Position.zero(),
+    positions: new Map(),
+    buffer_updates: false,
+    colours: new Map(),
+    default_cell_size: 128,
+    update_col_row_size() {},
+    update_focus_tooltip() {},
+    toolbar: { update() {} },
+});
+`, context);
+
+/// Imports `string` in the given format (the name of a `QuiverImportExport`, e.g. `tikz_cd`) into a
+/// new diagram, returning the UI, and the result of the import.
+const import_diagram = (format, string) => {
+    const ui = context.create_ui();
+    const result = context.QuiverImportExport[format].import(ui, string);
+    return { ui, result };
+};
+
+/// Exports a diagram in the given format (as named by `Quiver.export`, e.g. `tikz-cd`), with the
+/// given settings, which are otherwise disabled.
+const export_diagram = (ui, format, settings = {}) => {
+    return ui.quiver.export(
+        format,
+        { get: (key) => settings.hasOwnProperty(key) ? settings[key] : false },
+        { macro_url: null, dimensions: null },
+        { colours: new Map(), colours_reversed: new Map() },
+    );
+};
+
+/// Returns the names of the files in the corpus with the given extension, without the extension.
+const corpus = (extension) => {
+    return fs.readdirSync(CORPUS)
+        .filter((file) => file.endsWith(extension))
+        .map((file) => file.slice(0, -extension.length))
+        .sort();
+};
+
+/// Returns the files in the corpus that contain diagrams, in any format that may be imported.
+const diagrams = () => {
+    return fs.readdirSync(CORPUS).filter((file) => /\.(?:tex|txt|dot)$/.test(file)).sort();
+};
+
+/// Reads a file from the corpus.
+const read_corpus = (file) => fs.readFileSync(path.join(CORPUS, file), "utf8");
+
+/// Imports a diagram from the corpus, using the format corresponding to its extension.
+const import_corpus = (file) => {
+    const format = { ".tex": "tikz_cd", ".txt": "text", ".dot": "dot" }[path.extname(file)];
+    return import_diagram(format, read_corpus(file));
+};
+
+/// Returns the expected output stored in `golden/<file>`. If the `UPDATE_GOLDEN` environment
+/// variable is set, the file is first overwritten with `actual`, which is useful when the output
+/// has been changed deliberately.
+const golden = (file, actual) => {
+    const destination = path.join(GOLDEN, file);
+    if (process.env.UPDATE_GOLDEN) {
+        fs.mkdirSync(path.dirname(destination), { recursive: true });
+        fs.writeFileSync(destination, actual);
+    }
+    return fs.readFileSync(destination, "utf8");
+};
+
+module.exports = {
+    context,
+    import_diagram,
+    export_diagram,
+    corpus,
+    diagrams,
+    read_corpus,
+    import_corpus,
+    golden,
+};
@@ -0,0 +1,102 @@
+// Tests for the text format. Every diagram in the corpus must survive being printed and parsed
+// again, both as text and as the diagram itself (which we compare using the base64 format). Parse
+// errors must be reported at the correct line and column.
+
+const assert = require("assert");
+const test = require("node:test");
+const { import_diagram, export_diagram, diagrams, import_corpus } = require("./harness.js");
+
+// Returns the text format and base64 encoding of a diagram.
+const encodings = (ui) => [export_diagram(ui, "text").data, export_diagram(ui, "base64").data];
+
+for (const file of diagrams()) {
+    test(`text round trip: ${file}`, () => {
+        const { ui } = import_corpus(file);
+        const [text, base64] = encodings(ui);
+        assert.deepStrictEqual(encodings(import_diagram("text", text).ui), [text, base64]);
+    });
+}
+
+test("text import: JSON options", () => {
+    const { ui } = import_diagram("text", `quiver {
+        A & B [{"label_colour": [120, 60, 60]}]
+        A -> B : "f" [{"colour": [240, 60, 60], "style": {"body": {"name": "dashed"}}}]
+        A -> B : "g" [{"curve": 2, "offset": -1, "label_position": 30, "level": 2}]
+        A -> B : "h" [{"label_alignment": "right"}, {"style": {"head": {"name": "epi"}}}]
+    }`);
+    assert.strictEqual(export_diagram(ui, "text").data, `quiver {
+    A & B [{"label_colour":[120,60,60]}]
+    A -> B : "f" [dashed, {"colour":[240,60,60]}]
+    A -> B : "g" [pos=0.3, offset=-1, curve=2, level=2]
+    A -> B : "h" [swap, two heads]
+}`);
+});
+
+test("text import: named attributes take precedence over JSON options", () => {
+    const { ui } = import_diagram("text", `quiver {
+        A & B
+        A -> B [curve=1, shorten source=10, tail, {
+            "curve": 2,
+            "shorten": {"source": 30, "target": 40},
+            "style": {"tail": {"name": "maps to"}, "body": {"name": "dotted"}}
+        }]
+    }`);
+    assert.strictEqual(export_diagram(ui, "text").data, `quiver {
+    A & B
+    A -> B [curve=1, shorten source=10, shorten target=40, tail, dotted]
+}`);
+});
+
+test("text import: `quiver` may be followed by a newline", () => {
+    const { ui } = import_diagram("text", `# A comment.
+quiver
+{
+    A & B
+    A -> B [{"curve": 2}]
+}`);
+    assert.strictEqual(export_diagram(ui, "text").data, `quiver {
+    A & B
+    A -> B [curve=2]
+}`);
+});
+
+test("text import: errors are reported with their line and column", () => {
+    // A diagram with the objects `A` and `B` on its second line, followed by the given lines.
+    const diagram = (...lines) => ["quiver {", "    A & B", ...lines, "}"].join("\n");
+    const ERRORS = [
+        [diagram("    A -> C"), "line 3, column 10: `C` has not been declared"],
+        [diagram("    A -> B [bogus]"), "line 3, column 13: unknown arrow attribute `bogus`"],
+        [diagram("    B : \"B"), "line 3, column 9: unterminated label"],
+        [diagram("    A -> B [{\"curve\": }]"), "line 3, column 13: invalid JSON options"],
+        // Errors following JSON options spanning multiple lines.
+        [
+            diagram("    A -> B [{", "        \"curve\": 1", "    }, bogus]"),
+            "line 5, column 8: unknown arrow attribute `bogus`",
+        ],
+        [
+            diagram("    A -> B [{", "        \"curve\": 1", "    }]", "    A -> C"),
+            "line 6, column 10: `C` has not been declared",
+        ],
+        // Invalid JSON options are reported at the start of the options.
+        [diagram("    A -> B [{\"level\": 0}]"), "line 3, column 13: invalid value for `level`"],
+        [
+            diagram("    A -> B [swap, {\"curve\": 1.5}]"),
+            "line 3, column 19: invalid value for `curve`",
+        ],
+        [
+            diagram("    A -> B [{\"label_alignment\": \"bogus\"}]"),
+            "line 3, column 13: invalid value for `label_alignment`",
+        ],
+        [
+            diagram("    A -> B [{\"style\": {\"name\": \"bogus\"}}]"),
+            "line 3, column 13: invalid value for `style`",
+        ],
+        [
+            diagram("    A -> B [shorten source=90, {\"shorten\": {\"target\": 50}}]"),
+            "line 3, column 7: the arrow is shortened by more than 100%",
+        ],
+    ];
+    for (const [text, message] of ERRORS) {
+        assert.throws(() => import_diagram("text", text), { message }, text);
+    }
+});
@@ -0,0 +1,61 @@
+// Tests for importing and exporting tikz-cd. Each diagram in the corpus is imported, and its
+// structure compared against the text format in `golden/tikz-cd`. Exporting and importing again
+// must then reproduce the same export, and diagrams exported by quiver (which begin with a link to
+// quiver) must be reproduced verbatim.
+
+const assert = require("assert");
+const test = require("node:test");
+const { import_diagram, export_diagram, corpus, read_corpus, import_corpus, golden } =
+    require("./harness.js");
+
+for (const name of corpus(".tex")) {
+    test(`tikz-cd round trip: ${name}`, () => {
+        const { ui, result } = import_corpus(`${name}.tex`);
+        const text = export_diagram(ui, "text").data;
+        assert.strictEqual(text, golden(`tikz-cd/${name}.txt`, `${text}\n`).trimEnd());
+
+        // Shortening is converted between proportions and lengths, so the proportions (and hence
+        // the link to the diagram) may be rounded on import, but the tikz-cd itself is stable.
+        const tikz_cd = export_diagram(ui, "tikz-cd").data;
+        const reimported = import_diagram("tikz_cd", tikz_cd);
+        assert.deepStrictEqual([...reimported.result.metadata.tikz_incompatibilities], []);
+        const without_link = (tikz_cd) => tikz_cd.replace(/^% https:\/\/q\.uiver\.app\/.*\n/, "");
+        assert.strictEqual(
+            without_link(export_diagram(reimported.ui, "tikz-cd").data),
+            without_link(tikz_cd),
+        );
+
+        const source = read_corpus(`${name}.tex`);
+        if (source.startsWith("% https://q.uiver.app/")) {
+            assert.deepStrictEqual([...result.metadata.tikz_incompatibilities], []);
+            assert.strictEqual(tikz_cd, source.trimEnd());
+        }
+    });
+}
+
+test("tikz-cd import: older arrow syntax matches the option syntax", () => {
+    const older = import_diagram("tikz_cd", String.raw`\begin{tikzcd}
+        A \arrow{r}{f} \ar{d}[swap]{g} & B \dar{h} \\
+        C \rar[dashed]{k} & D \ular[description]{} \uar{}[near end]{h'}
+    \end{tikzcd}`);
+    const options = import_diagram("tikz_cd", String.raw`\begin{tikzcd}
+        A \arrow[r, "f"] \ar[d, "g"'] & B \arrow[d, "h"] \\
+        C \arrow[r, dashed, "k"] & D \arrow[ul, description] \arrow[u, "h'" near end]
+    \end{tikzcd}`);
+    assert.deepStrictEqual([...older.result.metadata.tikz_incompatibilities], []);
+    assert.strictEqual(
+        export_diagram(older.ui, "tikz-cd").data,
+        export_diagram(options.ui, "tikz-cd").data,
+    );
+});
+
+test("tikz-cd import: arrows without options do not leak into labels", () => {
+    const { ui, result } = import_diagram("tikz_cd", String.raw`\begin{tikzcd}
+        A \arrow & B
+    \end{tikzcd}`);
+    assert.deepStrictEqual([...result.metadata.tikz_incompatibilities], ["arrows without options"]);
+    assert.deepStrictEqual(
+        Array.from(ui.quiver.cells[0], (vertex) => vertex.label),
+        ["A", "B"],
+    );
+});
@@ -0,0 +1,28 @@
+// Golden-file tests for exporting Typst. The export of each diagram in the corpus is compared
+// against the corresponding file in `golden/typst`. Between them, these cover curves, offsets,
+// label positions and alignments, arrow styles, colours, labels that must be passed through as raw
+// LaTeX, and the comments describing features that fletcher does not support.
+
+const assert = require("assert");
+const path = require("path");
+const test = require("node:test");
+const { import_diagram, export_diagram, diagrams, import_corpus, golden } = require("./harness.js");
+
+for (const file of diagrams()) {
+    test(`Typst export: ${file}`, () => {
+        const { ui } = import_corpus(file);
+        const typst = export_diagram(ui, "typst").data;
+        const name = path.basename(file, path.extname(file));
+        assert.strictEqual(typst, golden(`typst/${name}.typ`, `${typst}\n`).trimEnd());
+    });
+}
+
+test("Typst export: commas and semicolons in function arguments", () => {
+    const { ui } = import_diagram("text", String.raw`quiver {
+        A : "\mathbf{a,b}" & B : "\frac{a,b}{c;d}" & C : "F(x, y)"
+    }`);
+    const typst = export_diagram(ui, "typst").data;
+    assert.match(typst, /\$bold\(a "," b\)\$/);
+    assert.match(typst, /\$frac\(a "," b, c ";" d\)\$/);
+    assert.match(typst, /\$F \( x , y \)\$/);
+});
@@ -0,0 +1,49 @@
+// Tests for exporting xy-pic. If a LaTeX distribution with xy-pic is installed, the export of each
+// diagram in the corpus is compiled under `\usepackage[all]{xy}`; otherwise the tests are skipped.
+
+const assert = require("assert");
+const child_process = require("child_process");
+const fs = require("fs");
+const os = require("os");
+const path = require("path");
+const test = require("node:test");
+const { export_diagram, diagrams, import_corpus } = require("./harness.js");
+
+// Whether `pdflatex` and xy-pic are installed.
+const latex = child_process.spawnSync("pdflatex", ["--version"]).error === undefined
+    && child_process.spawnSync("kpsewhich", ["xy.sty"]).stdout.toString().trim() !== "";
+
+const document = (xypic) => String.raw`\documentclass{article}
+\usepackage{amsmath}
+\usepackage{amssymb}
+\usepackage[all]{xy}
+% Macros used by labels in the corpus.
+\newcommand{\mycmd}{M}
+\newcommand{\weird}[1]{#1}
+\begin{document}
+\[
+${xypic}
+\]
+\end{document}
+`;
+
+for (const file of diagrams()) {
+    test(`xy-pic export compiles: ${file}`, { skip: !latex && "pdflatex is not installed" }, () => {
+        const { ui } = import_corpus(file);
+        const directory = fs.mkdtempSync(path.join(os.tmpdir(), "quiver-xypic-"));
+        try {
+            fs.writeFileSync(
+                path.join(directory, "diagram.tex"),
+                document(export_diagram(ui, "xypic").data),
+            );
+            const result = child_process.spawnSync(
+                "pdflatex",
+                ["-interaction=nonstopmode", "-halt-on-error", "diagram.tex"],
+                { cwd: directory },
+            );
+            assert.strictEqual(result.status, 0, result.stdout.toString());
+        } finally {
+            fs.rmSync(directory, { recursive: true, force: true });
+        }
+    });
+}